@@ -0,0 +1,216 @@
+use serde::de::{DeserializeOwned, Error};
+use serde_json;
+use std::io::{self, BufRead, BufReader, Read};
+use std::marker::PhantomData;
+
+use types::Card;
+use ScryfallError;
+
+/// Iterator over the items of a bulk data file, which is one big JSON array.
+///
+/// Only the item being decoded is held in memory, so a file of several hundred megabytes can
+/// be walked with the memory of a single card. Created by `ScryfallApi::download_bulk_data`,
+/// or with `BulkStream::new` for a file already on disk.
+///
+/// An item that doesn't decode as a `T` is reported as an error and skipped, so one odd card
+/// doesn't cost the rest of the file. Input that isn't a JSON array, or failing to read it, ends
+/// the stream.
+pub struct BulkStream<R, T = Card> {
+    reader: BufReader<R>,
+    /// Bytes of the item being read
+    buf: Vec<u8>,
+    started: bool,
+    done: bool,
+    item: PhantomData<T>,
+}
+
+impl<R, T> BulkStream<R, T>
+where
+    R: Read,
+    T: DeserializeOwned,
+{
+    pub fn new(reader: R) -> BulkStream<R, T> {
+        BulkStream {
+            reader: BufReader::new(reader),
+            buf: Vec::new(),
+            started: false,
+            done: false,
+            item: PhantomData,
+        }
+    }
+
+    /// Read the next item into `buf`. False at the end of the array.
+    fn next_item(&mut self) -> Result<bool, ScryfallError> {
+        self.skip_whitespace()?;
+        if !self.started {
+            self.started = true;
+            if self.next_byte()? != Some(b'[') {
+                return Err(malformed("expected a JSON array"));
+            }
+            self.skip_whitespace()?;
+            if self.peek_byte()? == Some(b']') {
+                self.done = true;
+                return Ok(false);
+            }
+        } else {
+            match self.next_byte()? {
+                Some(b',') => self.skip_whitespace()?,
+                Some(b']') => {
+                    self.done = true;
+                    return Ok(false);
+                }
+                _ => return Err(malformed("expected `,` or `]` after an item")),
+            }
+        }
+        self.read_value()?;
+        Ok(true)
+    }
+
+    /// Copy one JSON value into `buf`, tracking just enough of the syntax to find where it ends.
+    fn read_value(&mut self) -> Result<(), ScryfallError> {
+        self.buf.clear();
+        let mut depth = 0usize;
+        let mut in_string = false;
+        let mut escaped = false;
+        loop {
+            let byte = match self.peek_byte()? {
+                Some(byte) => byte,
+                None => return Err(malformed("unexpected end of file")),
+            };
+            if in_string {
+                if escaped {
+                    escaped = false;
+                } else if byte == b'\\' {
+                    escaped = true;
+                } else if byte == b'"' {
+                    in_string = false;
+                }
+            } else {
+                match byte {
+                    b'"' => in_string = true,
+                    b'{' | b'[' => depth += 1,
+                    b'}' | b']' if depth > 0 => depth -= 1,
+                    // A number, `true` etc. ends at whatever comes after it
+                    b',' | b']' | b' ' | b'\t' | b'\n' | b'\r' if depth == 0 => return Ok(()),
+                    _ => {}
+                }
+            }
+            self.buf.push(byte);
+            self.reader.consume(1);
+            if depth == 0 && !in_string && matches!(byte, b'}' | b']' | b'"') {
+                return Ok(());
+            }
+        }
+    }
+
+    fn peek_byte(&mut self) -> io::Result<Option<u8>> {
+        Ok(self.reader.fill_buf()?.first().cloned())
+    }
+
+    fn next_byte(&mut self) -> io::Result<Option<u8>> {
+        let byte = self.peek_byte()?;
+        if byte.is_some() {
+            self.reader.consume(1);
+        }
+        Ok(byte)
+    }
+
+    fn skip_whitespace(&mut self) -> io::Result<()> {
+        while let Some(byte) = self.peek_byte()? {
+            if !byte.is_ascii_whitespace() {
+                break;
+            }
+            self.reader.consume(1);
+        }
+        Ok(())
+    }
+}
+
+impl<R, T> Iterator for BulkStream<R, T>
+where
+    R: Read,
+    T: DeserializeOwned,
+{
+    type Item = Result<T, ScryfallError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.next_item() {
+            Ok(true) => Some(serde_json::from_slice(&self.buf).map_err(ScryfallError::from)),
+            Ok(false) => None,
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+fn malformed(message: &str) -> ScryfallError {
+    ScryfallError::Json(serde_json::Error::custom(message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    #[test]
+    fn streams_cards_one_at_a_time() {
+        let json = include_str!("../testcases/card_search_result.json");
+        let page: Value = serde_json::from_str(json).expect("Parse search page");
+        let array = serde_json::to_string_pretty(&page["data"]).unwrap();
+
+        let cards: Vec<Card> = BulkStream::new(array.as_bytes())
+            .map(|card| card.expect("Parse Card"))
+            .collect();
+        assert_eq!(175, cards.len());
+        assert_eq!("Acolyte of the Inferno", cards[0].name);
+    }
+
+    #[test]
+    fn bad_card_is_skipped() {
+        let json = include_str!("../testcases/card_current.json");
+        let mut bad: Value = serde_json::from_str(json).unwrap();
+        bad.as_object_mut().unwrap().remove("name");
+        let array = format!("[{}, {}]", bad, json);
+
+        let mut stream = BulkStream::<_, Card>::new(array.as_bytes());
+        assert!(stream.next().unwrap().is_err());
+        let card = stream.next().unwrap().expect("Parse current Card");
+        assert_eq!("Merfolk Looter", card.name);
+        assert!(stream.next().is_none());
+    }
+
+    #[test]
+    fn tricky_values() {
+        let json = r#" [ "a\"]", {"b": ["}", 1]} ,2, true ,null] "#;
+        let values: Vec<Value> = BulkStream::new(json.as_bytes())
+            .collect::<Result<_, _>>()
+            .expect("Parse values");
+        assert_eq!(
+            vec![
+                Value::from("a\"]"),
+                serde_json::json!({"b": ["}", 1]}),
+                Value::from(2),
+                Value::from(true),
+                Value::Null,
+            ],
+            values
+        );
+        assert_eq!(0, BulkStream::<_, Value>::new(&b"[ ]"[..]).count());
+    }
+
+    #[test]
+    fn malformed_input_ends_with_an_error() {
+        let mut stream = BulkStream::<_, Value>::new(&br#"[{"a": 1}, {"b": "#[..]);
+        assert!(stream.next().unwrap().is_ok());
+        assert!(stream.next().unwrap().is_err());
+        assert!(stream.next().is_none());
+
+        let mut stream = BulkStream::<_, Value>::new(&br#"{"object": "list"}"#[..]);
+        assert!(stream.next().unwrap().is_err());
+    }
+}
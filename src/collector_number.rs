@@ -0,0 +1,140 @@
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// A card's number within its set, like `"65"`, `"82a"`, `"12★"` or `"A-4"`.
+///
+/// Kept exactly as Scryfall writes it, but split into a prefix, a number and a suffix so a set
+/// sorts the way it's printed: `2` before `10`, `82` before `82a` before `83`. Numbers without
+/// any digits sort after all those with them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CollectorNumber(String);
+
+impl CollectorNumber {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Whatever comes before the digits, like the `A-` of Alchemy rebalanced cards
+    pub fn prefix(&self) -> &str {
+        &self.0[..self.digits_start()]
+    }
+
+    /// The numeric part, if there is one
+    pub fn number(&self) -> Option<u32> {
+        let start = self.digits_start();
+        self.0[start..self.digits_end()].parse().ok()
+    }
+
+    /// Whatever comes after the digits, like a variant letter or a `★`
+    pub fn suffix(&self) -> &str {
+        &self.0[self.digits_end()..]
+    }
+
+    fn digits_start(&self) -> usize {
+        self.0
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(self.0.len())
+    }
+
+    fn digits_end(&self) -> usize {
+        let start = self.digits_start();
+        self.0[start..]
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(self.0.len(), |end| start + end)
+    }
+
+    fn sort_key(&self) -> (bool, Option<u32>, &str, &str, &str) {
+        let number = self.number();
+        (
+            number.is_none(),
+            number,
+            self.prefix(),
+            self.suffix(),
+            &self.0,
+        )
+    }
+}
+
+impl Ord for CollectorNumber {
+    fn cmp(&self, other: &CollectorNumber) -> Ordering {
+        self.sort_key().cmp(&other.sort_key())
+    }
+}
+
+impl PartialOrd for CollectorNumber {
+    fn partial_cmp(&self, other: &CollectorNumber) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<u32> for CollectorNumber {
+    fn from(number: u32) -> CollectorNumber {
+        CollectorNumber(number.to_string())
+    }
+}
+
+impl<'a> From<&'a str> for CollectorNumber {
+    fn from(number: &'a str) -> CollectorNumber {
+        CollectorNumber(number.to_string())
+    }
+}
+
+impl From<String> for CollectorNumber {
+    fn from(number: String) -> CollectorNumber {
+        CollectorNumber(number)
+    }
+}
+
+impl fmt::Display for CollectorNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Serialize for CollectorNumber {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for CollectorNumber {
+    fn deserialize<D>(deserializer: D) -> Result<CollectorNumber, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(CollectorNumber)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parts() {
+        let n = CollectorNumber::from("82a");
+        assert_eq!(("", Some(82), "a"), (n.prefix(), n.number(), n.suffix()));
+        let n = CollectorNumber::from("12★");
+        assert_eq!(("", Some(12), "★"), (n.prefix(), n.number(), n.suffix()));
+        let n = CollectorNumber::from("A-4");
+        assert_eq!(("A-", Some(4), ""), (n.prefix(), n.number(), n.suffix()));
+        let n = CollectorNumber::from("SHM");
+        assert_eq!(("SHM", None, ""), (n.prefix(), n.number(), n.suffix()));
+    }
+
+    #[test]
+    fn natural_ordering() {
+        let mut numbers: Vec<CollectorNumber> = vec!["SHM", "83", "10", "82a", "12★", "2", "82"]
+            .into_iter()
+            .map(CollectorNumber::from)
+            .collect();
+        numbers.sort();
+        let sorted: Vec<&str> = numbers.iter().map(|n| n.as_str()).collect();
+        assert_eq!(vec!["2", "10", "12★", "82", "82a", "83", "SHM"], sorted);
+    }
+}
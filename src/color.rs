@@ -0,0 +1,301 @@
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// One of the five colors of Magic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Color {
+    #[serde(rename = "W")]
+    White,
+    #[serde(rename = "U")]
+    Blue,
+    #[serde(rename = "B")]
+    Black,
+    #[serde(rename = "R")]
+    Red,
+    #[serde(rename = "G")]
+    Green,
+}
+
+/// Every color, in WUBRG order
+pub const ALL_COLORS: [Color; 5] = [
+    Color::White,
+    Color::Blue,
+    Color::Black,
+    Color::Red,
+    Color::Green,
+];
+
+impl Color {
+    /// The color's single letter abbreviation, as used by Scryfall
+    pub fn letter(self) -> char {
+        match self {
+            Color::White => 'W',
+            Color::Blue => 'U',
+            Color::Black => 'B',
+            Color::Red => 'R',
+            Color::Green => 'G',
+        }
+    }
+
+    pub fn from_letter(letter: char) -> Option<Color> {
+        match letter.to_ascii_uppercase() {
+            'W' => Some(Color::White),
+            'U' => Some(Color::Blue),
+            'B' => Some(Color::Black),
+            'R' => Some(Color::Red),
+            'G' => Some(Color::Green),
+            _ => None,
+        }
+    }
+
+    fn bit(self) -> u8 {
+        1 << (self as u8)
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.letter())
+    }
+}
+
+/// A set of colors, like a card's colors or color identity. Empty means colorless.
+///
+/// Serializes to and from Scryfall's arrays of color letters (`["U", "R"]`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ColorSet(u8);
+
+/// Guild, shard, wedge and Nephilim names with the colors they stand for
+const NAMES: [(&str, &str); 25] = [
+    ("azorius", "WU"),
+    ("dimir", "UB"),
+    ("rakdos", "BR"),
+    ("gruul", "RG"),
+    ("selesnya", "WG"),
+    ("orzhov", "WB"),
+    ("izzet", "UR"),
+    ("golgari", "BG"),
+    ("boros", "WR"),
+    ("simic", "UG"),
+    ("bant", "WUG"),
+    ("esper", "WUB"),
+    ("grixis", "UBR"),
+    ("jund", "BRG"),
+    ("naya", "WRG"),
+    ("abzan", "WBG"),
+    ("jeskai", "WUR"),
+    ("sultai", "UBG"),
+    ("mardu", "WBR"),
+    ("temur", "URG"),
+    ("glint", "UBRG"),
+    ("dune", "WBRG"),
+    ("ink", "WURG"),
+    ("witch", "WUBG"),
+    ("yore", "WUBR"),
+];
+
+impl ColorSet {
+    /// No colors at all
+    pub fn colorless() -> ColorSet {
+        ColorSet(0)
+    }
+
+    /// All five colors
+    pub fn all() -> ColorSet {
+        ALL_COLORS.iter().cloned().collect()
+    }
+
+    pub fn insert(&mut self, color: Color) {
+        self.0 |= color.bit();
+    }
+
+    pub fn contains(self, color: Color) -> bool {
+        self.0 & color.bit() != 0
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// True for colorless
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn is_multicolored(self) -> bool {
+        self.len() > 1
+    }
+
+    /// True if every color in `self` is also in `other`. This is the Commander deck building
+    /// check: a card can go in a deck when its identity is a subset of the commander's.
+    pub fn is_subset(self, other: ColorSet) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    pub fn is_superset(self, other: ColorSet) -> bool {
+        other.is_subset(self)
+    }
+
+    pub fn union(self, other: ColorSet) -> ColorSet {
+        ColorSet(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: ColorSet) -> ColorSet {
+        ColorSet(self.0 & other.0)
+    }
+
+    /// The colors in WUBRG order
+    pub fn iter(self) -> impl Iterator<Item = Color> {
+        ALL_COLORS
+            .iter()
+            .cloned()
+            .filter(move |c| self.contains(*c))
+    }
+
+    /// The guild (2 colors), shard or wedge (3 colors) or Nephilim (4 colors) name of this
+    /// combination, like `"izzet"` or `"esper"`.
+    pub fn name(self) -> Option<&'static str> {
+        NAMES
+            .iter()
+            .find(|&&(_, colors)| colors.parse() == Ok(self))
+            .map(|&(name, _)| name)
+    }
+}
+
+impl ::std::iter::FromIterator<Color> for ColorSet {
+    fn from_iter<I: IntoIterator<Item = Color>>(colors: I) -> ColorSet {
+        let mut set = ColorSet::colorless();
+        for color in colors {
+            set.insert(color);
+        }
+        set
+    }
+}
+
+impl From<Color> for ColorSet {
+    fn from(color: Color) -> ColorSet {
+        ColorSet(color.bit())
+    }
+}
+
+/// Letters are written in WUBRG order, and colorless is written as `C`.
+impl fmt::Display for ColorSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "C");
+        }
+        for color in self.iter() {
+            write!(f, "{}", color)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseColorSetError(String);
+
+impl fmt::Display for ParseColorSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "not a color combination: {:?}", self.0)
+    }
+}
+
+impl ::std::error::Error for ParseColorSetError {}
+
+/// Accepts color letters in any order and case (`"ur"`, `"RU"`), `"c"`/`"colorless"`, and the
+/// names returned by `ColorSet::name`, which is what Scryfall's `c:` and `id:` searches accept.
+impl FromStr for ColorSet {
+    type Err = ParseColorSetError;
+
+    fn from_str(s: &str) -> Result<ColorSet, ParseColorSetError> {
+        let lower = s.to_ascii_lowercase();
+        if lower == "c" || lower == "colorless" {
+            return Ok(ColorSet::colorless());
+        }
+        if let Some(&(_, colors)) = NAMES.iter().find(|&&(name, _)| name == lower) {
+            return colors.parse();
+        }
+        let colors: Option<ColorSet> = match lower.as_str() {
+            "white" => Some(Color::White.into()),
+            "blue" => Some(Color::Blue.into()),
+            "black" => Some(Color::Black.into()),
+            "red" => Some(Color::Red.into()),
+            "green" => Some(Color::Green.into()),
+            _ => s.chars().map(Color::from_letter).collect(),
+        };
+        match colors {
+            Some(colors) if !s.is_empty() => Ok(colors),
+            _ => Err(ParseColorSetError(s.to_string())),
+        }
+    }
+}
+
+impl Serialize for ColorSet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for color in self.iter() {
+            seq.serialize_element(&color)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ColorSet {
+    fn deserialize<D>(deserializer: D) -> Result<ColorSet, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let colors: Vec<Color> = Vec::deserialize(deserializer)?;
+        Ok(colors.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    fn colors(s: &str) -> ColorSet {
+        s.parse().expect("Parse ColorSet")
+    }
+
+    #[test]
+    fn commander_identity_checks() {
+        let commander = colors("esper");
+        assert!(colors("WU").is_subset(commander));
+        assert!(colors("c").is_subset(commander));
+        assert!(!colors("UR").is_subset(commander));
+        assert!(commander.is_superset(colors("B")));
+    }
+
+    #[test]
+    fn display_is_wubrg_ordered() {
+        assert_eq!("UR", colors("ru").to_string());
+        assert_eq!("WUBRG", ColorSet::all().to_string());
+        assert_eq!("C", ColorSet::colorless().to_string());
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(Some("izzet"), colors("RU").name());
+        assert_eq!(Some("abzan"), colors("GWB").name());
+        assert_eq!(Some("glint"), colors("UBRG").name());
+        assert_eq!(None, colors("U").name());
+        assert_eq!(colors("WUR"), colors("Jeskai"));
+        assert!("purple".parse::<ColorSet>().is_err());
+        assert!("".parse::<ColorSet>().is_err());
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let set: ColorSet = serde_json::from_str(r#"["R", "U"]"#).expect("Parse ColorSet");
+        assert_eq!(colors("UR"), set);
+        assert_eq!(r#"["U","R"]"#, serde_json::to_string(&set).unwrap());
+        assert!(serde_json::from_str::<ColorSet>(r#"["P"]"#).is_err());
+    }
+}
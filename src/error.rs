@@ -0,0 +1,62 @@
+use reqwest;
+use serde_json;
+use std::error;
+use std::fmt;
+use std::io;
+
+use types::ErrorObject;
+
+/// Everything that can go wrong while talking to Scryfall
+#[derive(Debug)]
+pub enum ScryfallError {
+    /// The HTTP request could not be completed (DNS, connection, TLS, ...)
+    Transport(reqwest::Error),
+    /// The response body was not the JSON we expected
+    Json(serde_json::Error),
+    /// Scryfall understood the request, and answered with an error object
+    Api(Box<ErrorObject>),
+    /// Reading or writing local files (like the image cache) failed
+    Io(io::Error),
+}
+
+impl fmt::Display for ScryfallError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ScryfallError::Transport(ref e) => write!(f, "transport error: {}", e),
+            ScryfallError::Json(ref e) => write!(f, "could not decode response: {}", e),
+            ScryfallError::Api(ref e) => {
+                write!(f, "scryfall error {} ({}): {}", e.status, e.code, e.details)
+            }
+            ScryfallError::Io(ref e) => write!(f, "i/o error: {}", e),
+        }
+    }
+}
+
+impl error::Error for ScryfallError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            ScryfallError::Transport(ref e) => Some(e),
+            ScryfallError::Json(ref e) => Some(e),
+            ScryfallError::Api(_) => None,
+            ScryfallError::Io(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<reqwest::Error> for ScryfallError {
+    fn from(e: reqwest::Error) -> ScryfallError {
+        ScryfallError::Transport(e)
+    }
+}
+
+impl From<serde_json::Error> for ScryfallError {
+    fn from(e: serde_json::Error) -> ScryfallError {
+        ScryfallError::Json(e)
+    }
+}
+
+impl From<io::Error> for ScryfallError {
+    fn from(e: io::Error) -> ScryfallError {
+        ScryfallError::Io(e)
+    }
+}
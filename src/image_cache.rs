@@ -0,0 +1,454 @@
+use serde_json;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use url::Url;
+
+use types::{Card, Image, ImageFace, ImageVersion};
+use {ScryfallApi, ScryfallError};
+
+const INDEX_FILE: &str = "index.json";
+const OBJECTS_DIR: &str = "objects";
+
+/// Card images kept on disk, so the same scan is only downloaded once.
+///
+/// Image files are named after a hash of their contents, and `index.json` maps card id +
+/// version + face to one of them. Faces sharing a picture (like both halves of a split card)
+/// share the file. An entry is refreshed when the `?timestamp` Scryfall appends to the image URI
+/// changes, and the least recently used images are deleted once the cache grows past its cap.
+/// Recency from cache hits is saved with the next download, by `flush`, or when the cache is
+/// dropped.
+pub struct ImageCache {
+    dir: PathBuf,
+    max_bytes: u64,
+    index: Mutex<Index>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Index {
+    /// Incremented on every hit or insert; an entry's `last_used` is the clock at that time
+    clock: u64,
+    entries: HashMap<String, Entry>,
+    /// Changed since it was last saved
+    #[serde(skip)]
+    dirty: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Entry {
+    /// File name of the image under `objects/`
+    hash: String,
+    /// Cache-buster of the URI the image was downloaded from, if it had one
+    timestamp: Option<String>,
+    size: u64,
+    last_used: u64,
+}
+
+impl ImageCache {
+    /// Open (or create) a cache in `dir` holding at most `max_bytes` of images. A missing or
+    /// unreadable `index.json` empties the cache.
+    pub fn open<P: AsRef<Path>>(dir: P, max_bytes: u64) -> io::Result<ImageCache> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(dir.join(OBJECTS_DIR))?;
+        let index = match fs::read(dir.join(INDEX_FILE)) {
+            Ok(raw) => serde_json::from_slice(&raw).ok(),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => return Err(e),
+        };
+        // Image files are named by content, so without an index there's no telling which card
+        // they belong to. Start cold rather than keep files nothing counts against the cap.
+        let discarded = index.is_none();
+        for file in fs::read_dir(dir.join(OBJECTS_DIR))? {
+            let path = file?.path();
+            // Left over from a write that didn't finish
+            if discarded || path.extension().is_some_and(|ext| ext == "tmp") {
+                fs::remove_file(path)?;
+            }
+        }
+        match fs::remove_file(dir.join(format!("{}.tmp", INDEX_FILE))) {
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => {}
+            other => other?,
+        }
+        Ok(ImageCache {
+            dir,
+            max_bytes,
+            index: Mutex::new(index.unwrap_or_default()),
+        })
+    }
+
+    /// One face of a card's image, from disk if we have an up to date copy, from Scryfall if not.
+    pub fn get(
+        &self,
+        api: &ScryfallApi,
+        card: &Card,
+        version: ImageVersion,
+        face: ImageFace,
+    ) -> Result<Image, ScryfallError> {
+        let key = format!("{}-{}-{}", card.id, version.as_str(), face.as_str());
+        let timestamp = card
+            .image_uri(version, face)
+            .and_then(|uri| cache_buster(uri));
+
+        let cached = self.read(&mut self.lock(), &key, timestamp.as_deref())?;
+        if let Some(image) = cached {
+            return Ok(image);
+        }
+        // Download without the lock, so other threads' hits don't wait for us. Two threads
+        // missing the same image both download it, and the second write is a no-op.
+        let image = api.card_image(card, version, face)?;
+        self.write(&mut self.lock(), key, timestamp, &image)?;
+        Ok(image)
+    }
+
+    /// Save recency from cache hits since the last download. Also done on drop.
+    pub fn flush(&self) -> io::Result<()> {
+        let mut index = self.lock();
+        if index.dirty {
+            self.save(&mut index)?;
+        }
+        Ok(())
+    }
+
+    /// Bytes of images currently on disk
+    pub fn size(&self) -> u64 {
+        self.lock().total_bytes()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Index> {
+        self.index.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn object_path(&self, hash: &str) -> PathBuf {
+        self.dir.join(OBJECTS_DIR).join(hash)
+    }
+
+    fn read(
+        &self,
+        index: &mut Index,
+        key: &str,
+        timestamp: Option<&str>,
+    ) -> io::Result<Option<Image>> {
+        let hash = match index.entries.get(key) {
+            Some(entry) if entry.timestamp.as_deref() == timestamp => entry.hash.clone(),
+            _ => return Ok(None),
+        };
+        match fs::read(self.object_path(&hash)) {
+            Ok(bytes) => {
+                // Only `last_used` changed, which isn't worth rewriting the index for every hit
+                index.touch(key);
+                Ok(Some(Image(bytes)))
+            }
+            // Somebody cleaned up the directory behind our back
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => {
+                index.entries.remove(key);
+                index.dirty = true;
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    fn write(
+        &self,
+        index: &mut Index,
+        key: String,
+        timestamp: Option<String>,
+        image: &Image,
+    ) -> io::Result<()> {
+        let hash = format!("{:016x}", fnv1a(&image.0));
+        let path = self.object_path(&hash);
+        let size = image.0.len() as u64;
+        // A file of the wrong size is left over from a write that didn't finish
+        if fs::metadata(&path).map(|m| m.len()).ok() != Some(size) {
+            // Write then rename, so a crash never leaves half an image behind
+            let tmp = self.object_path(&format!("{}.tmp", hash));
+            fs::write(&tmp, &image.0)?;
+            fs::rename(tmp, path)?;
+        }
+
+        let stale = index.entries.insert(
+            key.clone(),
+            Entry {
+                hash,
+                timestamp,
+                size,
+                last_used: 0,
+            },
+        );
+        if let Some(stale) = stale {
+            self.remove_unreferenced(index, &stale.hash)?;
+        }
+        index.touch(&key);
+
+        self.evict(index)?;
+        self.save(index)
+    }
+
+    /// Drop least recently used entries until we're under `max_bytes`
+    fn evict(&self, index: &mut Index) -> io::Result<()> {
+        while index.total_bytes() > self.max_bytes {
+            let oldest = index
+                .entries
+                .iter()
+                .min_by_key(|&(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+            let entry = match oldest.and_then(|key| index.entries.remove(&key)) {
+                Some(entry) => entry,
+                None => break,
+            };
+            self.remove_unreferenced(index, &entry.hash)?;
+        }
+        Ok(())
+    }
+
+    fn remove_unreferenced(&self, index: &Index, hash: &str) -> io::Result<()> {
+        if index.entries.values().any(|entry| entry.hash == hash) {
+            return Ok(());
+        }
+        match fs::remove_file(self.object_path(hash)) {
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            other => other,
+        }
+    }
+
+    fn save(&self, index: &mut Index) -> io::Result<()> {
+        // Write then rename, so a crash never leaves half an index behind
+        let tmp = self.dir.join(format!("{}.tmp", INDEX_FILE));
+        fs::write(&tmp, serde_json::to_vec(index)?)?;
+        fs::rename(tmp, self.dir.join(INDEX_FILE))?;
+        index.dirty = false;
+        Ok(())
+    }
+}
+
+impl Drop for ImageCache {
+    fn drop(&mut self) {
+        // Nowhere to report a failure to; at worst the next run evicts in a slightly wrong order
+        let _ = self.flush();
+    }
+}
+
+impl Index {
+    fn touch(&mut self, key: &str) {
+        self.clock += 1;
+        if let Some(entry) = self.entries.get_mut(key) {
+            entry.last_used = self.clock;
+        }
+        self.dirty = true;
+    }
+
+    /// Size of every distinct image file, counting shared files once
+    fn total_bytes(&self) -> u64 {
+        let files: HashMap<&str, u64> = self
+            .entries
+            .values()
+            .map(|entry| (entry.hash.as_str(), entry.size))
+            .collect();
+        files.values().sum()
+    }
+}
+
+/// The `1521725642` in `https://img.scryfall.com/cards/png/en/a25/65.png?1521725642`
+fn cache_buster(uri: &str) -> Option<String> {
+    Url::parse(uri).ok()?.query().map(|q| q.to_string())
+}
+
+/// 64 bit FNV-1a. Plenty to tell card scans apart, and stable across Rust releases unlike
+/// `DefaultHasher`, which matters for names that live on disk.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+    use ScryfallResponse;
+
+    fn cache(name: &str, max_bytes: u64) -> ImageCache {
+        let dir = env::temp_dir().join(format!(
+            "scryfall-image-cache-{}-{}",
+            name,
+            ::std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        ImageCache::open(dir, max_bytes).expect("Open image cache")
+    }
+
+    fn cached(cache: &ImageCache, key: &str, timestamp: Option<&str>) -> Option<Image> {
+        let mut index = cache.lock();
+        cache
+            .read(&mut index, key, timestamp)
+            .expect("Read image cache")
+    }
+
+    fn store(cache: &ImageCache, key: &str, timestamp: Option<&str>, bytes: &[u8]) {
+        let mut index = cache.lock();
+        cache
+            .write(
+                &mut index,
+                key.to_string(),
+                timestamp.map(|t| t.to_string()),
+                &Image(bytes.to_vec()),
+            )
+            .expect("Write image cache");
+    }
+
+    #[test]
+    fn hit_after_store_and_reopen() {
+        let c = cache("reopen", 1024);
+        store(&c, "a-normal-front", Some("1521725642"), b"looter");
+        let c = ImageCache::open(&c.dir, 1024).unwrap();
+        assert_eq!(
+            Some(Image(b"looter".to_vec())),
+            cached(&c, "a-normal-front", Some("1521725642"))
+        );
+    }
+
+    #[test]
+    fn hits_are_saved_on_flush() {
+        let c = cache("flush", 1024);
+        store(&c, "a", None, b"aaaa");
+        store(&c, "b", None, b"bbbb");
+        let index = fs::read(c.dir.join(INDEX_FILE)).unwrap();
+        assert!(cached(&c, "a", None).is_some());
+        assert_eq!(index, fs::read(c.dir.join(INDEX_FILE)).unwrap());
+        c.flush().unwrap();
+        assert_ne!(index, fs::read(c.dir.join(INDEX_FILE)).unwrap());
+    }
+
+    #[test]
+    fn truncated_file_is_replaced() {
+        let c = cache("truncated", 1024);
+        let hash = format!("{:016x}", fnv1a(b"looter"));
+        fs::write(c.object_path(&hash), b"loo").unwrap();
+        store(&c, "a", None, b"looter");
+        assert_eq!(Some(Image(b"looter".to_vec())), cached(&c, "a", None));
+    }
+
+    #[test]
+    fn new_timestamp_is_a_miss_and_replaces_the_file() {
+        let c = cache("stale", 1024);
+        store(&c, "a-normal-front", Some("1"), b"old art");
+        assert_eq!(None, cached(&c, "a-normal-front", Some("2")));
+        store(&c, "a-normal-front", Some("2"), b"new art");
+        assert_eq!(7, c.size());
+        assert_eq!(1, fs::read_dir(c.dir.join(OBJECTS_DIR)).unwrap().count());
+    }
+
+    #[test]
+    fn identical_images_share_a_file() {
+        let c = cache("shared", 1024);
+        store(&c, "split-normal-front", None, b"fire // ice");
+        store(&c, "split-normal-back", None, b"fire // ice");
+        assert_eq!(11, c.size());
+        assert_eq!(1, fs::read_dir(c.dir.join(OBJECTS_DIR)).unwrap().count());
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let c = cache("lru", 10);
+        store(&c, "a", None, b"aaaa");
+        store(&c, "b", None, b"bbbb");
+        assert!(cached(&c, "a", None).is_some());
+        store(&c, "c", None, b"cccc");
+        assert!(cached(&c, "a", None).is_some());
+        assert_eq!(None, cached(&c, "b", None));
+        assert!(cached(&c, "c", None).is_some());
+        assert_eq!(8, c.size());
+    }
+
+    /// A local image server, so `get` can be tested without Scryfall. Serves `/aaaa` as `aaaa`
+    /// and so on, and counts the requests it answered.
+    fn serve() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Bind image server");
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut lines = BufReader::new(&stream).lines().map(Result::unwrap);
+                let request = lines.next().unwrap_or_default();
+                // Read the headers too, closing with them unread can reset the connection
+                lines.take_while(|line| !line.is_empty()).for_each(drop);
+                let path = request.split([' ', '?']).nth(1).unwrap_or("/");
+                let body = &path[1..];
+                counter.fetch_add(1, Ordering::SeqCst);
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+            }
+        });
+        (base, requests)
+    }
+
+    #[test]
+    fn get_hits_evicts_and_recovers_from_a_corrupt_index() {
+        let (base, requests) = serve();
+        let mut card =
+            Card::parse(include_bytes!("../testcases/card.json")).expect("Parse Card JSON");
+        card.image_uris = Some(
+            [("small", "aaaa"), ("normal", "bbbb"), ("large", "cccc")]
+                .iter()
+                .map(|&(version, path)| (version.to_string(), format!("{}/{}?1", base, path)))
+                .collect(),
+        );
+        let api = ScryfallApi::with_wait(Duration::from_millis(0));
+        let get = |c: &ImageCache, version| {
+            let image = c
+                .get(&api, &card, version, ImageFace::Front)
+                .expect("Get image");
+            String::from_utf8(image.0).unwrap()
+        };
+
+        let c = cache("get", 10);
+        assert_eq!("aaaa", get(&c, ImageVersion::Small));
+        assert_eq!("bbbb", get(&c, ImageVersion::Normal));
+        assert_eq!("aaaa", get(&c, ImageVersion::Small));
+        assert_eq!(2, requests.load(Ordering::SeqCst));
+
+        // Over the cap, so the least recently used `normal` goes
+        assert_eq!("cccc", get(&c, ImageVersion::Large));
+        assert_eq!(8, c.size());
+        assert_eq!("aaaa", get(&c, ImageVersion::Small));
+        assert_eq!(3, requests.load(Ordering::SeqCst));
+        assert_eq!("bbbb", get(&c, ImageVersion::Normal));
+        assert_eq!(4, requests.load(Ordering::SeqCst));
+
+        let dir = c.dir.clone();
+        drop(c);
+        fs::write(dir.join(INDEX_FILE), b"{\"clock\": ").unwrap();
+        fs::write(dir.join(OBJECTS_DIR).join("0123456789abcdef.tmp"), b"aa").unwrap();
+        let c = ImageCache::open(&dir, 10).unwrap();
+        assert_eq!(0, c.size());
+        assert_eq!(0, fs::read_dir(dir.join(OBJECTS_DIR)).unwrap().count());
+        assert_eq!("aaaa", get(&c, ImageVersion::Small));
+        assert_eq!(5, requests.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn cache_buster_from_uri() {
+        assert_eq!(
+            Some("1521725642".to_string()),
+            cache_buster("https://img.scryfall.com/cards/png/en/a25/65.png?1521725642")
+        );
+        assert_eq!(
+            None,
+            cache_buster("https://img.scryfall.com/cards/png/en/a25/65.png")
+        );
+    }
+}
@@ -1,45 +1,201 @@
 extern crate chrono;
 extern crate reqwest;
+extern crate rust_decimal;
 extern crate serde;
 extern crate serde_json;
 #[macro_use]
 extern crate serde_derive;
 extern crate url;
+extern crate uuid;
 
-mod requests;
-mod types;
+#[macro_use]
+mod macros;
+
+mod bulk;
+mod collector_number;
+mod color;
+mod error;
+mod image_cache;
+mod pagination;
+pub mod query;
+pub mod requests;
+pub mod types;
+
+pub use bulk::BulkStream;
+pub use error::ScryfallError;
+pub use image_cache::ImageCache;
+pub use pagination::Paginator;
+
+use serde::de::DeserializeOwned;
+
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
 
 /// Base URL
 const SCRYFALL_API: &str = "https://api.scryfall.com";
 
 /// Number of milliseconds between each call, at a minimum
-const SCRYFALL_DEFAULT_WAIT: i32 = 50;
+const SCRYFALL_DEFAULT_WAIT: u64 = 50;
 
 pub struct ScryfallApi {
     base_url: String,
-    last_request: Option<chrono::DateTime<chrono::Utc>>,
+    /// Minimum time between the start of two requests
+    wait: Duration,
+    /// When the last request was sent. The lock is held while sleeping, so threads sharing a
+    /// client queue up behind each other instead of all firing once the wait is over.
+    last_request: Mutex<Option<Instant>>,
+    /// Called with the URI and warnings of every response that has any
+    on_warnings: Option<Box<WarningsHook>>,
 }
 
+/// See `ScryfallApi::on_warnings`
+pub type WarningsHook = dyn Fn(&str, &[String]) + Send + Sync;
+
 impl ScryfallApi {
     pub fn new() -> ScryfallApi {
+        ScryfallApi::with_wait(Duration::from_millis(SCRYFALL_DEFAULT_WAIT))
+    }
+
+    /// A client that leaves at least `wait` between requests, instead of the default 50ms.
+    pub fn with_wait(wait: Duration) -> ScryfallApi {
         ScryfallApi {
             base_url: SCRYFALL_API.to_string(),
-            last_request: None,
+            wait,
+            last_request: Mutex::new(None),
+            on_warnings: None,
+        }
+    }
+
+    /// Call `hook` with the URI and warnings of every response Scryfall attached warnings to,
+    /// like a search where part of the query was ignored. Without a hook they are only available
+    /// on the response itself, like `ListObject::warnings`.
+    pub fn on_warnings<F>(mut self, hook: F) -> ScryfallApi
+    where
+        F: Fn(&str, &[String]) + Send + Sync + 'static,
+    {
+        self.on_warnings = Some(Box::new(hook));
+        self
+    }
+
+    /// Block until at least `self.wait` has passed since the previous request.
+    fn throttle(&self) {
+        // A panic while holding the lock can't leave the timestamp in a bad state
+        let mut last_request = self.last_request.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(last) = *last_request {
+            let elapsed = last.elapsed();
+            if elapsed < self.wait {
+                thread::sleep(self.wait - elapsed);
+            }
         }
+        *last_request = Some(Instant::now());
     }
 
-    pub fn run<T>(&self, req: T) -> Result<T::Response, reqwest::Error>
+    pub fn run<T>(&self, req: T) -> Result<T::Response, ScryfallError>
     where
         T: ScryfallRequest + std::fmt::Debug,
     {
         let url = format!("{}{}", self.base_url, req.path());
-        let client = reqwest::ClientBuilder::new().build().unwrap();
-        println!("Running {:?} - Fetching from: {:?}", req, url);
-        let result = client.get(&url).send();
-        result.and_then(|mut ok| ok.text()).map(|raw| {
-            println!("{:?}", raw);
-            T::Response::parse(&raw)
-        })
+        self.fetch(&url)
+    }
+
+    /// Fetch an absolute Scryfall API URI, like a list's `next_page`.
+    pub fn fetch<R>(&self, uri: &str) -> Result<R, ScryfallError>
+    where
+        R: ScryfallResponse,
+    {
+        let client = reqwest::ClientBuilder::new().build()?;
+        self.throttle();
+        let mut response = client.get(uri).send()?;
+        let mut raw = Vec::new();
+        response.copy_to(&mut raw)?;
+        if !response.status().is_success() {
+            // Scryfall explains its own errors, but a CDN or proxy error page is no response at
+            // all, even if it would parse (like an HTML page as an `Image`)
+            if let Ok(error) = serde_json::from_slice::<types::ErrorObject>(&raw) {
+                return Err(ScryfallError::Api(Box::new(error)));
+            }
+            response.error_for_status_ref()?;
+        }
+        let response = R::parse(&raw)?;
+        self.report_warnings(uri, response.warnings());
+        Ok(response)
+    }
+
+    fn report_warnings(&self, uri: &str, warnings: &[String]) {
+        match self.on_warnings {
+            Some(ref hook) if !warnings.is_empty() => hook(uri, warnings),
+            _ => {}
+        }
+    }
+
+    /// Lazily walk every page of a list request, one item at a time.
+    ///
+    /// Pages are only fetched once the previous one has been consumed, and go through the same
+    /// rate limiting as `run`.
+    pub fn paginate<T, R>(&self, req: R) -> Paginator<'_, T>
+    where
+        R: ScryfallRequest<Response = types::ListObject<T>>,
+        T: DeserializeOwned,
+    {
+        let url = format!("{}{}", self.base_url, req.path());
+        Paginator::new(self, url)
+    }
+
+    /// The rulings for a card, fetched through its `rulings_uri`.
+    pub fn card_rulings(
+        &self,
+        card: &types::Card,
+    ) -> Result<types::ListObject<types::Ruling>, ScryfallError> {
+        self.fetch(&card.rulings_uri)
+    }
+
+    /// The full card behind one of a card's `all_parts` entries.
+    pub fn related_card(&self, related: &types::RelatedCard) -> Result<types::Card, ScryfallError> {
+        self.fetch(&related.uri)
+    }
+
+    /// Download one face of a card's image. Uses the card's own `image_uris` when it has them,
+    /// and falls back to asking the API for the image by id.
+    pub fn card_image(
+        &self,
+        card: &types::Card,
+        version: types::ImageVersion,
+        face: types::ImageFace,
+    ) -> Result<types::Image, ScryfallError> {
+        match card.image_uri(version, face) {
+            Some(uri) => self.fetch(uri),
+            None => self.run(requests::CardImage(card.id, version, face)),
+        }
+    }
+
+    /// Start downloading a bulk data file, to be read one item at a time. Use `Card` for every
+    /// file but `BulkDataType::Rulings`, which holds `Ruling`s.
+    pub fn download_bulk_data<T>(
+        &self,
+        item: &types::BulkDataItem,
+    ) -> Result<BulkStream<reqwest::Response, T>, ScryfallError>
+    where
+        T: DeserializeOwned,
+    {
+        let client = reqwest::ClientBuilder::new().build()?;
+        self.throttle();
+        let response = client.get(&item.download_uri).send()?.error_for_status()?;
+        Ok(BulkStream::new(response))
+    }
+
+    /// Keep paginating from a list page that was already fetched.
+    pub fn paginate_from<T>(&self, list: types::ListObject<T>) -> Paginator<'_, T>
+    where
+        T: DeserializeOwned,
+    {
+        Paginator::from_list(self, list)
+    }
+}
+
+impl Default for ScryfallApi {
+    fn default() -> ScryfallApi {
+        ScryfallApi::new()
     }
 }
 
@@ -48,13 +204,66 @@ pub trait ScryfallRequest {
     fn path(&self) -> String;
 }
 
-pub trait ScryfallResponse {
-    fn parse(raw: &str) -> Self;
+pub trait ScryfallResponse: Sized {
+    /// Decode a response body. Scryfall error objects come back as `ScryfallError::Api`.
+    fn parse(raw: &[u8]) -> Result<Self, ScryfallError>;
+
+    /// Non-fatal warnings Scryfall sent along with the response. Only lists have any.
+    fn warnings(&self) -> &[String] {
+        &[]
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn throttle_spaces_requests() {
+        let a = ScryfallApi::with_wait(Duration::from_millis(100));
+        let start = Instant::now();
+        a.throttle();
+        a.throttle();
+        a.throttle();
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn throttle_spaces_requests_across_threads() {
+        let a = Arc::new(ScryfallApi::with_wait(Duration::from_millis(100)));
+        let start = Instant::now();
+        let handles: Vec<_> = (0..3)
+            .map(|_| {
+                let a = a.clone();
+                thread::spawn(move || a.throttle())
+            })
+            .collect();
+        for h in handles {
+            h.join().unwrap();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn warnings_hook() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        let a = ScryfallApi::new().on_warnings(move |uri, warnings| {
+            sink.lock()
+                .unwrap()
+                .push((uri.to_string(), warnings.to_vec()));
+        });
+        a.report_warnings("/cards/search?q=is%3Ashiny", &["ignored".to_string()]);
+        a.report_warnings("/cards/search?q=c%3Ar", &[]);
+        assert_eq!(
+            vec![(
+                "/cards/search?q=is%3Ashiny".to_string(),
+                vec!["ignored".to_string()]
+            )],
+            *seen.lock().unwrap()
+        );
+    }
 
     #[test]
     fn sets_request() {
@@ -75,6 +284,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn cards_request() {
         let a = ScryfallApi::new();
         match a.run(requests::Cards) {
@@ -83,12 +293,6 @@ mod tests {
         }
     }
 
-    // TODO: How to handle negative search results?
-    // {"object": "error",
-    //  "code": "not_found",
-    //  "type": "ambiguous",
-    //  "status": 404,
-    //  "details": "Too many cards match ambiguous name “aus com”. Add more words to refine your search."
     #[test]
     fn card_named_request() {
         let a = ScryfallApi::new();
@@ -101,6 +305,91 @@ mod tests {
         }
     }
 
+    #[test]
+    fn card_named_ambiguous_request() {
+        let a = ScryfallApi::new();
+        match a.run(requests::CardNamed(
+            "aus com".to_string(),
+            requests::Exact::Fuzzy,
+        )) {
+            Ok(card) => panic!("Expected an ambiguous match, got {}", card.name),
+            Err(ScryfallError::Api(e)) => assert_eq!("not_found", e.code),
+            Err(e) => panic!("Failed to fetch: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn card_in_set_request() {
+        let a = ScryfallApi::new();
+        match a.run(requests::CardInSet::new("a25".to_string(), 65)) {
+            Ok(card) => assert_eq!("Merfolk Looter".to_string(), card.name),
+            Err(e) => panic!("Failed to fetch: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn card_rulings_request() {
+        let a = ScryfallApi::new();
+        let card = a.run(requests::CardInSet::new("a25".to_string(), 65));
+        match card.and_then(|card| a.card_rulings(&card)) {
+            Ok(_list) => println!("Worked"),
+            Err(e) => panic!("Failed to fetch: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn parse_mana_request() {
+        let a = ScryfallApi::new();
+        match a.run(requests::ParseMana("RGX".to_string())) {
+            Ok(cost) => assert_eq!("{X}{R}{G}".to_string(), cost.cost),
+            Err(e) => panic!("Failed to fetch: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn catalog_request() {
+        let a = ScryfallApi::new();
+        match a.run(requests::Catalog(requests::CatalogKind::Supertypes)) {
+            Ok(catalog) => assert!(catalog.data.contains(&"Legendary".to_string())),
+            Err(e) => panic!("Failed to fetch: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn related_card_request() {
+        let a = ScryfallApi::new();
+        let card = a.run(requests::CardNamed(
+            "Young Pyromancer".to_string(),
+            requests::Exact::Exact,
+        ));
+        let token = card.and_then(|card| {
+            let parts = card.all_parts.expect("Young Pyromancer makes tokens");
+            let token = parts
+                .iter()
+                .find(|part| part.component == Some(types::RelatedCardComponent::Token))
+                .expect("Elemental token");
+            a.related_card(token)
+        });
+        match token {
+            Ok(token) => assert_eq!("Elemental".to_string(), token.name),
+            Err(e) => panic!("Failed to fetch: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn card_image_request() {
+        let a = ScryfallApi::new();
+        let req = requests::CardImage(
+            "8cae1a42-052e-4110-9afc-d3ec83b7c8a9".parse().unwrap(),
+            types::ImageVersion::Small,
+            types::ImageFace::Front,
+        );
+        match a.run(req) {
+            Ok(image) => assert!(image.0.starts_with(b"\xff\xd8")), // JPEG magic number
+            Err(e) => panic!("Failed to fetch: {:?}", e),
+        }
+    }
+
     #[test]
     fn card_autocomplete() {
         let a = ScryfallApi::new();
@@ -110,6 +399,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn card_search_paginate() {
+        let a = ScryfallApi::new();
+        let req = requests::CardSearch("c:r pow=3".to_string().into(), Default::default());
+        let cards: Result<Vec<_>, _> = a.paginate(req).max_pages(2).collect();
+        match cards {
+            Ok(cards) => assert!(cards.len() > 175),
+            Err(e) => panic!("Failed to fetch: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn bulk_data_request() {
+        let a = ScryfallApi::new();
+        match a.run(requests::BulkData) {
+            Ok(list) => assert!(list
+                .data()
+                .iter()
+                .any(|item| item.bulk_type == types::BulkDataType::OracleCards)),
+            Err(e) => panic!("Failed to fetch: {:?}", e),
+        }
+    }
+
     #[test]
     fn card_search() {
         let a = ScryfallApi::new();
@@ -117,7 +429,7 @@ mod tests {
             "c:r pow=3".to_string().into(),
             Default::default(),
         )) {
-            Ok(catalog) => println!("Worked"),
+            Ok(_list) => println!("Worked"),
             Err(e) => panic!("Failed to fetch: {:?}", e),
         }
     }
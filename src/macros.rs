@@ -0,0 +1,108 @@
+/// An enum for one of Scryfall's computer-readable strings, like a rarity or a layout.
+///
+/// Every variant is paired with the string Scryfall uses for it. Strings we don't know about
+/// yet become `Unknown(String)` instead of failing to deserialize, so a new layout or frame on
+/// Scryfall's side doesn't break every response that contains it. Generates `as_str`, `From<&str>`,
+/// `Display`, `Serialize` and `Deserialize`.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident => $value:expr,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+            /// A value this crate doesn't know about yet
+            Unknown(String),
+        }
+
+        impl $name {
+            /// The string Scryfall uses for this value
+            pub fn as_str(&self) -> &str {
+                match *self {
+                    $( $name::$variant => $value, )*
+                    $name::Unknown(ref value) => value,
+                }
+            }
+        }
+
+        impl<'a> From<&'a str> for $name {
+            fn from(value: &'a str) -> $name {
+                match value {
+                    $( $value => $name::$variant, )*
+                    other => $name::Unknown(other.to_string()),
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<$name, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+                Ok($name::from(value.as_str()))
+            }
+        }
+    };
+}
+
+/// A newtype around a UUID, so ids of different kinds of objects can't be mixed up.
+///
+/// Serializes as the hyphenated string Scryfall uses, and generates `Display`, `FromStr` and
+/// `From<Uuid>`. Expects a `ParseIdError` in scope.
+macro_rules! uuid_id {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident;
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+        pub struct $name(pub ::uuid::Uuid);
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = ParseIdError;
+
+            fn from_str(s: &str) -> Result<$name, ParseIdError> {
+                ::uuid::Uuid::parse_str(s)
+                    .map($name)
+                    .map_err(|_| ParseIdError(s.to_string()))
+            }
+        }
+
+        impl From<::uuid::Uuid> for $name {
+            fn from(uuid: ::uuid::Uuid) -> $name {
+                $name(uuid)
+            }
+        }
+    };
+}
@@ -0,0 +1,132 @@
+use serde::de::DeserializeOwned;
+use std::vec;
+
+use types::ListObject;
+use {ScryfallApi, ScryfallError};
+
+/// Iterator over every item of a paginated list, following `next_page` as it goes.
+///
+/// Created by `ScryfallApi::paginate` or `ScryfallApi::paginate_from`. A failed page fetch is
+/// yielded once as an `Err`, after which the iterator is exhausted.
+pub struct Paginator<'a, T> {
+    api: &'a ScryfallApi,
+    /// Items of the current page that haven't been handed out yet
+    current: vec::IntoIter<T>,
+    /// The page to fetch once `current` runs dry, if there is one
+    next_page: Option<String>,
+    pages_fetched: usize,
+    items_yielded: usize,
+    max_pages: Option<usize>,
+    max_items: Option<usize>,
+}
+
+impl<'a, T> Paginator<'a, T>
+where
+    T: DeserializeOwned,
+{
+    pub(crate) fn new(api: &'a ScryfallApi, first_page: String) -> Paginator<'a, T> {
+        Paginator {
+            api,
+            current: Vec::new().into_iter(),
+            next_page: Some(first_page),
+            pages_fetched: 0,
+            items_yielded: 0,
+            max_pages: None,
+            max_items: None,
+        }
+    }
+
+    pub(crate) fn from_list(api: &'a ScryfallApi, list: ListObject<T>) -> Paginator<'a, T> {
+        let mut paginator = Paginator::new(api, String::new());
+        paginator.load(list);
+        paginator
+    }
+
+    /// Stop after yielding `max` items.
+    pub fn max_items(mut self, max: usize) -> Paginator<'a, T> {
+        self.max_items = Some(max);
+        self
+    }
+
+    /// Stop after `max` pages have been fetched (a page passed to `paginate_from` counts).
+    pub fn max_pages(mut self, max: usize) -> Paginator<'a, T> {
+        self.max_pages = Some(max);
+        self
+    }
+
+    fn load(&mut self, list: ListObject<T>) {
+        self.pages_fetched += 1;
+        self.next_page = if list.has_more { list.next_page } else { None };
+        self.current = list.data.into_iter();
+    }
+
+    fn may_fetch(&self) -> bool {
+        self.max_pages.map_or(true, |max| self.pages_fetched < max)
+    }
+}
+
+impl<'a, T> Iterator for Paginator<'a, T>
+where
+    T: DeserializeOwned,
+{
+    type Item = Result<T, ScryfallError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.max_items.is_some_and(|max| self.items_yielded >= max) {
+            return None;
+        }
+
+        loop {
+            if let Some(item) = self.current.next() {
+                self.items_yielded += 1;
+                return Some(Ok(item));
+            }
+
+            if !self.may_fetch() {
+                return None;
+            }
+
+            // Taking the uri means an error ends iteration instead of retrying forever
+            let uri = self.next_page.take()?;
+            match self.api.fetch(&uri) {
+                Ok(list) => self.load(list),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+    use types::Card;
+
+    fn search_page() -> ListObject<Card> {
+        let json = include_str!("../testcases/card_search_result.json");
+        serde_json::from_str(json).expect("Parse ListObject<Card>")
+    }
+
+    #[test]
+    fn max_items_stops_before_next_page() {
+        let a = ScryfallApi::new();
+        let cards: Vec<Card> = a
+            .paginate_from(search_page())
+            .max_items(10)
+            .map(|c| c.expect("No fetch should happen"))
+            .collect();
+        assert_eq!(10, cards.len());
+        assert_eq!("Acolyte of the Inferno", cards[0].name);
+    }
+
+    #[test]
+    fn max_pages_counts_the_initial_page() {
+        let a = ScryfallApi::new();
+        let cards: Vec<Card> = a
+            .paginate_from(search_page())
+            .max_pages(1)
+            .map(|c| c.expect("No fetch should happen"))
+            .collect();
+        assert_eq!(175, cards.len());
+    }
+}
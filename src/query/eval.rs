@@ -0,0 +1,359 @@
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::error::Error;
+use std::fmt;
+
+use super::{is_regex, Currency, Op, Query, Term};
+use types::{Card, ColorSet, Frame, FrameEffect, Layout, Legality};
+
+/// Flags `is:` can answer locally, besides layouts like `is:split`
+const SUPPORTED_FLAGS: [&str; 8] = [
+    "reserved",
+    "reprint",
+    "digital",
+    "fullart",
+    "highres",
+    "colorshifted",
+    "timeshifted",
+    "futureshifted",
+];
+
+/// A query used a keyword or value that can't be answered from card objects alone
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnsupportedPredicate(Query);
+
+impl UnsupportedPredicate {
+    /// The name or term that can't be answered, without the query around it
+    pub fn predicate(&self) -> &Query {
+        &self.0
+    }
+}
+
+impl fmt::Display for UnsupportedPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unsupported predicate: {}", self.0)
+    }
+}
+
+impl Error for UnsupportedPredicate {}
+
+/// Answering queries locally, against cards from bulk data or an earlier search.
+///
+/// Every card is judged on its own: nothing is deduplicated by name and extras aren't hidden,
+/// unlike a search on Scryfall with default options.
+impl Query {
+    /// Whether `card` matches this query.
+    pub fn matches(&self, card: &Card) -> Result<bool, UnsupportedPredicate> {
+        self.check_supported()?;
+        Ok(self.eval(card))
+    }
+
+    /// The cards that match this query, in their original order. Fails up front if any part of
+    /// the query is unsupported, even when there are no cards.
+    pub fn filter<'a, I>(&self, cards: I) -> Result<Vec<&'a Card>, UnsupportedPredicate>
+    where
+        I: IntoIterator<Item = &'a Card>,
+    {
+        self.check_supported()?;
+        Ok(cards.into_iter().filter(|card| self.eval(card)).collect())
+    }
+
+    fn check_supported(&self) -> Result<(), UnsupportedPredicate> {
+        match *self {
+            Query::And(ref queries) | Query::Or(ref queries) => {
+                queries.iter().try_for_each(|query| query.check_supported())
+            }
+            Query::Not(ref query) => query.check_supported(),
+            Query::Name(ref name) | Query::ExactName(ref name) if is_regex(name) => {
+                Err(UnsupportedPredicate(self.clone()))
+            }
+            Query::Name(_) | Query::ExactName(_) => Ok(()),
+            Query::Term(ref term) if term.is_supported() => Ok(()),
+            Query::Term(_) => Err(UnsupportedPredicate(self.clone())),
+        }
+    }
+
+    fn eval(&self, card: &Card) -> bool {
+        match *self {
+            Query::And(ref queries) => queries.iter().all(|query| query.eval(card)),
+            Query::Or(ref queries) => queries.iter().any(|query| query.eval(card)),
+            Query::Not(ref query) => !query.eval(card),
+            Query::Name(ref name) => contains(&card.name, name),
+            Query::ExactName(ref name) => (0..card.face_count())
+                .filter_map(|i| card.face_name(i))
+                .chain(Some(card.name.as_str()))
+                .any(|face| face.eq_ignore_ascii_case(name)),
+            Query::Term(ref term) => term.eval(card),
+        }
+    }
+}
+
+impl Term {
+    fn is_supported(&self) -> bool {
+        match *self {
+            Term::Is(ref flag) => {
+                SUPPORTED_FLAGS.contains(&flag.to_ascii_lowercase().as_str())
+                    || layout(flag).is_some()
+            }
+            // Regular expressions would need a regex engine
+            Term::Type(ref text) | Term::Oracle(ref text) => !is_regex(text),
+            Term::Other(..) => false,
+            _ => true,
+        }
+    }
+
+    fn eval(&self, card: &Card) -> bool {
+        match *self {
+            Term::Color(op, colors) => {
+                // `c:c` means colorless, not "at least no colors"
+                let op = match op {
+                    Op::Colon if colors.is_empty() => Op::Eq,
+                    Op::Colon => Op::GtEq,
+                    op => op,
+                };
+                compare_colors(op, card_colors(card), colors)
+            }
+            Term::Identity(op, colors) => {
+                let op = if op == Op::Colon { Op::LtEq } else { op };
+                compare_colors(op, card.color_identity, colors)
+            }
+            Term::Type(ref text) => card.type_line.as_ref().is_some_and(|t| contains(t, text)),
+            Term::Oracle(ref text) => (0..card.face_count()).any(|i| {
+                let oracle = card.face_oracle_text(i).unwrap_or("");
+                let name = card.face_name(i).unwrap_or(&card.name);
+                // `~` stands for the card's own name
+                contains(oracle, &text.replace('~', name))
+            }),
+            Term::ManaValue(op, value) => value.to_f64().is_some_and(|v| compare(op, card.cmc, v)),
+            Term::Power(op, value) => stats(card, |power, _| power).any(|p| compare(op, p, value)),
+            Term::Toughness(op, value) => {
+                stats(card, |_, toughness| toughness).any(|t| compare(op, t, value))
+            }
+            Term::Set(ref code) => card.set.eq_ignore_ascii_case(code),
+            Term::Rarity(op, ref rarity) => compare(op, &card.rarity, rarity),
+            Term::Legal(ref format) => card.is_legal_in(format.clone()),
+            Term::Banned(ref format) => card.legality(format) == Some(&Legality::Banned),
+            Term::Restricted(ref format) => card.legality(format) == Some(&Legality::Restricted),
+            Term::Price(currency, op, value) => {
+                let prices = card.prices();
+                let price = match currency {
+                    Currency::Usd => prices.usd,
+                    Currency::Eur => prices.eur,
+                    Currency::Tix => prices.tix,
+                };
+                price.is_some_and(|price| compare(op, price, value))
+            }
+            Term::Is(ref flag) => match flag.to_ascii_lowercase().as_str() {
+                "reserved" => card.reserved,
+                "reprint" => card.reprint,
+                "digital" => card.digital,
+                "fullart" => card.full_art,
+                "highres" => card.highres_image,
+                // Newer cards say so in their frame or set, older ones with a flag
+                "colorshifted" => {
+                    card.colorshifted
+                        || card
+                            .frame_effects
+                            .iter()
+                            .flatten()
+                            .any(|effect| *effect == FrameEffect::Colorshifted)
+                }
+                "timeshifted" => card.timeshifted || card.set == "tsb",
+                "futureshifted" => card.futureshifted || card.frame == Frame::Future,
+                _ => layout(flag).is_some_and(|layout| layout == card.layout),
+            },
+            Term::Other(..) => false,
+        }
+    }
+}
+
+/// The layout named by an `is:` flag, like `is:split` or `is:transform`
+fn layout(flag: &str) -> Option<Layout> {
+    match Layout::from(flag.to_ascii_lowercase().as_str()) {
+        Layout::Unknown(_) => None,
+        layout => Some(layout),
+    }
+}
+
+fn contains(haystack: &str, needle: &str) -> bool {
+    haystack.to_lowercase().contains(&needle.to_lowercase())
+}
+
+fn compare<T: PartialOrd>(op: Op, actual: T, expected: T) -> bool {
+    match op {
+        Op::Colon | Op::Eq => actual == expected,
+        Op::NotEq => actual != expected,
+        Op::Lt => actual < expected,
+        Op::LtEq => actual <= expected,
+        Op::Gt => actual > expected,
+        Op::GtEq => actual >= expected,
+    }
+}
+
+fn compare_colors(op: Op, actual: ColorSet, expected: ColorSet) -> bool {
+    match op {
+        Op::Colon | Op::Eq => actual == expected,
+        Op::NotEq => actual != expected,
+        Op::Lt => actual.is_subset(expected) && actual != expected,
+        Op::LtEq => actual.is_subset(expected),
+        Op::Gt => actual.is_superset(expected) && actual != expected,
+        Op::GtEq => actual.is_superset(expected),
+    }
+}
+
+/// A card's colors, which multi-faced cards only list per face
+fn card_colors(card: &Card) -> ColorSet {
+    card.colors.unwrap_or_else(|| {
+        card.card_faces
+            .iter()
+            .flatten()
+            .filter_map(|face| face.colors)
+            .fold(ColorSet::colorless(), ColorSet::union)
+    })
+}
+
+/// The numeric power or toughness of the card and each of its faces. `*` and friends are
+/// skipped, as Scryfall doesn't match them against numbers either.
+fn stats<'a, F>(card: &'a Card, pick: F) -> impl Iterator<Item = Decimal> + 'a
+where
+    F: Fn(&'a Option<String>, &'a Option<String>) -> &'a Option<String> + 'a,
+{
+    let faces = card.card_faces.iter().flatten();
+    Some(pick(&card.power, &card.toughness))
+        .into_iter()
+        .chain(faces.map(move |face| pick(&face.power, &face.toughness)))
+        .filter_map(|stat| stat.as_ref()?.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use types::ListObject;
+    use ScryfallResponse;
+
+    fn cards() -> Vec<Card> {
+        vec![
+            include_str!("../../testcases/card.json"),
+            include_str!("../../testcases/card_transform.json"),
+            include_str!("../../testcases/card_split.json"),
+            include_str!("../../testcases/card_all_parts.json"),
+        ]
+        .into_iter()
+        .map(|json| Card::parse(json.as_bytes()).expect("Parse Card JSON"))
+        .collect()
+    }
+
+    fn names(query: &str, cards: &[Card]) -> Vec<String> {
+        let query: Query = query.parse().expect("Parse query");
+        let matches = query.filter(cards).expect("Supported query");
+        matches.iter().map(|card| card.name.clone()).collect()
+    }
+
+    #[test]
+    fn search_page_matches_its_own_query() {
+        let json = include_str!("../../testcases/card_search_result.json");
+        let list = ListObject::<Card>::parse(json.as_bytes()).expect("Parse ListObject<Card>");
+        let query: Query = "c:r pow=3".parse().unwrap();
+        assert_eq!(175, query.filter(list.data()).unwrap().len());
+    }
+
+    #[test]
+    fn colors_and_identity() {
+        let cards = cards();
+        assert_eq!(
+            vec![
+                "Merfolk Looter",
+                "Delver of Secrets // Insectile Aberration",
+                "Fire // Ice"
+            ],
+            names("c:u", &cards)
+        );
+        assert_eq!(vec!["Fire // Ice"], names("c:ur", &cards));
+        assert_eq!(
+            vec![
+                "Merfolk Looter",
+                "Delver of Secrets // Insectile Aberration"
+            ],
+            names("c=u", &cards)
+        );
+        assert_eq!(4, names("id:izzet", &cards).len());
+        assert_eq!(vec!["Young Pyromancer"], names("id:r", &cards));
+        assert!(names("c:c", &cards).is_empty());
+    }
+
+    #[test]
+    fn faces_names_and_text() {
+        let cards = cards();
+        assert_eq!(vec!["Fire // Ice"], names("!ice", &cards));
+        assert_eq!(vec!["Fire // Ice"], names(r#"o:"~ deals 2""#, &cards));
+        assert_eq!(
+            vec!["Delver of Secrets // Insectile Aberration"],
+            names("pow>=3", &cards)
+        );
+        assert_eq!(vec!["Merfolk Looter"], names("looter t:merfolk", &cards));
+    }
+
+    #[test]
+    fn numbers_legality_and_flags() {
+        let cards = cards();
+        assert_eq!(
+            vec!["Merfolk Looter", "Young Pyromancer"],
+            names("mv=2", &cards)
+        );
+        assert_eq!(
+            vec![
+                "Merfolk Looter",
+                "Delver of Secrets // Insectile Aberration"
+            ],
+            names("f:pauper", &cards)
+        );
+        assert_eq!(
+            vec!["Merfolk Looter"],
+            names("is:reprint or usd<0.05", &cards)
+        );
+        assert_eq!(
+            vec!["Fire // Ice"],
+            names("is:split r>=uncommon -s:m14 -is:reprint", &cards)
+        );
+        assert_eq!(vec!["Young Pyromancer"], names("usd>0.5 -(c:u)", &cards));
+    }
+
+    #[test]
+    fn shifted_flags_on_current_cards() {
+        let json = include_str!("../../testcases/card_current.json");
+        let mut card = Card::parse(json.as_bytes()).expect("Parse Card JSON");
+        let shifted: Query = "is:timeshifted or is:colorshifted or is:futureshifted"
+            .parse()
+            .unwrap();
+        assert!(!shifted.matches(&card).unwrap());
+
+        card.set = "tsb".to_string();
+        assert!(Query::is("timeshifted").matches(&card).unwrap());
+
+        card.frame_effects = Some(vec![FrameEffect::Colorshifted]);
+        assert!(Query::is("colorshifted").matches(&card).unwrap());
+
+        card.frame = Frame::Future;
+        assert!(Query::is("futureshifted").matches(&card).unwrap());
+    }
+
+    #[test]
+    fn unsupported_predicates() {
+        let query: Query = "c:r (art:squirrel or pow>tou)".parse().unwrap();
+        let error = query.filter(&[]).expect_err("art: is unsupported");
+        assert_eq!("unsupported predicate: art:squirrel", error.to_string());
+
+        let query: Query = "is:foil".parse().unwrap();
+        assert!(query.matches(&cards()[0]).is_err());
+
+        let query: Query = "o:/^{T}:/".parse().unwrap();
+        assert!(query.matches(&cards()[0]).is_err());
+
+        let query: Query = "c:u /^merfolk/".parse().unwrap();
+        let error = query
+            .filter(&cards())
+            .expect_err("name regexes are unsupported");
+        assert_eq!(&Query::name("/^merfolk/"), error.predicate());
+        assert!(Query::exact_name("/looter$/").matches(&cards()[0]).is_err());
+    }
+}
@@ -0,0 +1,467 @@
+//! Scryfall search queries as a tree instead of a string.
+//!
+//! Build one from the constructors on `Query` and combine them with `&`, `|` and `!`:
+//!
+//! ```
+//! use scryfall_api::query::{Op, Query};
+//! use scryfall_api::types::Format;
+//!
+//! let query = (Query::type_line("goblin") | Query::type_line("wizard"))
+//!     & Query::power(Op::Eq, 3)
+//!     & Query::legal(Format::Modern)
+//!     & !Query::oracle("can't block");
+//! assert_eq!(
+//!     r#"(t:goblin or t:wizard) pow=3 f:modern -o:"can't block""#,
+//!     query.to_string()
+//! );
+//! ```
+//!
+//! The rendered string is what `requests::CardSearch` sends, via `CardSearchQuery::from`.
+//! Query strings written by hand can be parsed into the same tree with `str::parse`, and
+//! rendering a parsed query gives back a string that parses to the same tree. `Query::filter`
+//! answers a query against cards you already have, without asking Scryfall.
+
+use rust_decimal::Decimal;
+use std::fmt;
+use std::ops;
+use std::str::FromStr;
+
+use types::{ColorSet, Format, Rarity, SetCode};
+
+mod eval;
+mod parser;
+
+pub use self::eval::UnsupportedPredicate;
+pub use self::parser::{parse, ParseError};
+
+/// A Scryfall search, or one piece of one
+#[derive(Debug, Clone, PartialEq)]
+pub enum Query {
+    /// Every one of the queries must match. Written separated by spaces.
+    And(Vec<Query>),
+    /// At least one of the queries must match. Written separated by `or`.
+    Or(Vec<Query>),
+    /// The query must not match. Written with a leading `-`.
+    Not(Box<Query>),
+    /// A word or phrase that appears in the card's name
+    Name(String),
+    /// The card with exactly this name, written `!"Lightning Bolt"`
+    ExactName(String),
+    /// A `keyword:value` style search
+    Term(Term),
+}
+
+/// A single `keyword:value` search. Keywords have several spellings on Scryfall; each variant
+/// notes the one it is written with.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Term {
+    /// `c`: the card's colors
+    Color(Op, ColorSet),
+    /// `id`: the card's color identity
+    Identity(Op, ColorSet),
+    /// `t`: a word or phrase in the type line, or a regular expression like `/^legendary/`
+    Type(String),
+    /// `o`: a word or phrase in the Oracle text, or a regular expression like `/^{T}:/`
+    Oracle(String),
+    /// `mv`: mana value, previously converted mana cost
+    ManaValue(Op, Decimal),
+    /// `pow`
+    Power(Op, Decimal),
+    /// `tou`
+    Toughness(Op, Decimal),
+    /// `s`: printed in this set
+    Set(SetCode),
+    /// `r`
+    Rarity(Op, Rarity),
+    /// `f`: legal (or restricted) in this format
+    Legal(Format),
+    /// `banned`
+    Banned(Format),
+    /// `restricted`
+    Restricted(Format),
+    /// `usd`, `eur` or `tix`: the card's non-foil price
+    Price(Currency, Op, Decimal),
+    /// `is`: a flag like `is:reserved` or `is:split`. `not:` is written as a negated `is:`.
+    Is(String),
+    /// A keyword this crate doesn't model, like `art:squirrel` or `pow>tou`, kept as written
+    Other(String, Op, String),
+}
+
+/// How a term's value is compared. What `:` means depends on the keyword: "at least these
+/// colors" for `c`, "within these colors" for `id`, and equality for numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Op {
+    Colon,
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+/// The currencies Scryfall has prices in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Currency {
+    Usd,
+    Eur,
+    Tix,
+}
+
+impl Op {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Op::Colon => ":",
+            Op::Eq => "=",
+            Op::NotEq => "!=",
+            Op::Lt => "<",
+            Op::LtEq => "<=",
+            Op::Gt => ">",
+            Op::GtEq => ">=",
+        }
+    }
+}
+
+impl Currency {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Currency::Usd => "usd",
+            Currency::Eur => "eur",
+            Currency::Tix => "tix",
+        }
+    }
+}
+
+impl Query {
+    /// Name contains `name`
+    pub fn name<S: Into<String>>(name: S) -> Query {
+        Query::Name(name.into())
+    }
+
+    pub fn exact_name<S: Into<String>>(name: S) -> Query {
+        Query::ExactName(name.into())
+    }
+
+    pub fn color(op: Op, colors: ColorSet) -> Query {
+        Query::Term(Term::Color(op, colors))
+    }
+
+    pub fn identity(op: Op, colors: ColorSet) -> Query {
+        Query::Term(Term::Identity(op, colors))
+    }
+
+    /// Type line contains `text`
+    pub fn type_line<S: Into<String>>(text: S) -> Query {
+        Query::Term(Term::Type(text.into()))
+    }
+
+    /// Oracle text contains `text`
+    pub fn oracle<S: Into<String>>(text: S) -> Query {
+        Query::Term(Term::Oracle(text.into()))
+    }
+
+    pub fn mana_value<N: Into<Decimal>>(op: Op, value: N) -> Query {
+        Query::Term(Term::ManaValue(op, value.into()))
+    }
+
+    pub fn power<N: Into<Decimal>>(op: Op, value: N) -> Query {
+        Query::Term(Term::Power(op, value.into()))
+    }
+
+    pub fn toughness<N: Into<Decimal>>(op: Op, value: N) -> Query {
+        Query::Term(Term::Toughness(op, value.into()))
+    }
+
+    pub fn set<S: Into<SetCode>>(code: S) -> Query {
+        Query::Term(Term::Set(code.into()))
+    }
+
+    pub fn rarity(op: Op, rarity: Rarity) -> Query {
+        Query::Term(Term::Rarity(op, rarity))
+    }
+
+    pub fn legal(format: Format) -> Query {
+        Query::Term(Term::Legal(format))
+    }
+
+    pub fn banned(format: Format) -> Query {
+        Query::Term(Term::Banned(format))
+    }
+
+    pub fn restricted(format: Format) -> Query {
+        Query::Term(Term::Restricted(format))
+    }
+
+    pub fn price(currency: Currency, op: Op, value: Decimal) -> Query {
+        Query::Term(Term::Price(currency, op, value))
+    }
+
+    pub fn is<S: Into<String>>(flag: S) -> Query {
+        Query::Term(Term::Is(flag.into()))
+    }
+
+    /// `not:flag`, the same as `-is:flag`
+    pub fn not<S: Into<String>>(flag: S) -> Query {
+        !Query::is(flag)
+    }
+
+    /// Both this and `other`. Chained `and`s build a single flat `And`.
+    pub fn and(self, other: Query) -> Query {
+        match self {
+            Query::And(mut queries) => {
+                queries.push(other);
+                Query::And(queries)
+            }
+            query => Query::And(vec![query, other]),
+        }
+    }
+
+    /// Either this or `other`. Chained `or`s build a single flat `Or`.
+    pub fn or(self, other: Query) -> Query {
+        match self {
+            Query::Or(mut queries) => {
+                queries.push(other);
+                Query::Or(queries)
+            }
+            query => Query::Or(vec![query, other]),
+        }
+    }
+
+    /// Whether this query has to be wrapped in parentheses to be used inside `parent`
+    fn needs_parens_in(&self, parent: &Query) -> bool {
+        matches!(
+            (self, parent),
+            (&Query::And(_), &Query::And(_))
+                | (&Query::And(_), &Query::Not(_))
+                | (&Query::Or(_), _)
+                | (&Query::Not(_), &Query::Not(_))
+        )
+    }
+
+    fn fmt_child(&self, parent: &Query, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.needs_parens_in(parent) {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+}
+
+impl ops::BitAnd for Query {
+    type Output = Query;
+
+    fn bitand(self, other: Query) -> Query {
+        self.and(other)
+    }
+}
+
+impl ops::BitOr for Query {
+    type Output = Query;
+
+    fn bitor(self, other: Query) -> Query {
+        self.or(other)
+    }
+}
+
+impl ops::Not for Query {
+    type Output = Query;
+
+    fn not(self) -> Query {
+        Query::Not(Box::new(self))
+    }
+}
+
+impl FromStr for Query {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Query, ParseError> {
+        parse(s)
+    }
+}
+
+impl From<Term> for Query {
+    fn from(term: Term) -> Query {
+        Query::Term(term)
+    }
+}
+
+/// Renders Scryfall syntax, quoting any value that wouldn't survive as a bare word.
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Query::And(ref queries) | Query::Or(ref queries) => {
+                let separator = match *self {
+                    Query::And(_) => " ",
+                    _ => " or ",
+                };
+                for (i, query) in queries.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(separator)?;
+                    }
+                    query.fmt_child(self, f)?;
+                }
+                Ok(())
+            }
+            Query::Not(ref query) => {
+                f.write_str("-")?;
+                query.fmt_child(self, f)
+            }
+            Query::Name(ref name) => f.write_str(&quote(name)),
+            Query::ExactName(ref name) => write!(f, "!{}", quote(name)),
+            Query::Term(ref term) => term.fmt(f),
+        }
+    }
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Term::Color(op, colors) => write!(f, "c{}{}", op.as_str(), colors),
+            Term::Identity(op, colors) => write!(f, "id{}{}", op.as_str(), colors),
+            Term::Type(ref text) => write!(f, "t:{}", quote(text)),
+            Term::Oracle(ref text) => write!(f, "o:{}", quote(text)),
+            Term::ManaValue(op, value) => write!(f, "mv{}{}", op.as_str(), quote_number(value)),
+            Term::Power(op, value) => write!(f, "pow{}{}", op.as_str(), quote_number(value)),
+            Term::Toughness(op, value) => write!(f, "tou{}{}", op.as_str(), quote_number(value)),
+            Term::Set(ref code) => write!(f, "s:{}", quote(code)),
+            Term::Rarity(op, ref rarity) => write!(f, "r{}{}", op.as_str(), quote(rarity.as_str())),
+            Term::Legal(ref format) => write!(f, "f:{}", quote(format.as_str())),
+            Term::Banned(ref format) => write!(f, "banned:{}", quote(format.as_str())),
+            Term::Restricted(ref format) => write!(f, "restricted:{}", quote(format.as_str())),
+            Term::Price(currency, op, value) => write!(
+                f,
+                "{}{}{}",
+                currency.as_str(),
+                op.as_str(),
+                quote_number(value)
+            ),
+            Term::Is(ref flag) => write!(f, "is:{}", quote(flag)),
+            Term::Other(ref keyword, op, ref value) => {
+                write!(f, "{}{}{}", keyword, op.as_str(), quote(value))
+            }
+        }
+    }
+}
+
+fn quote_number(value: Decimal) -> String {
+    quote(&value.to_string())
+}
+
+/// Whether `value` is a regular expression, written between slashes like `/^{T}:/`. The first
+/// slash that isn't escaped with `\` has to be the last character, like the parser reads it.
+fn is_regex(value: &str) -> bool {
+    if !value.starts_with('/') {
+        return false;
+    }
+    let mut chars = value.char_indices().skip(1);
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '/' => return i + 1 == value.len(),
+            '\\' => {
+                chars.next();
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
+/// `value` as is if it reads back as a single bare word or is a regular expression, in double
+/// quotes otherwise
+fn quote(value: &str) -> String {
+    if is_regex(value) {
+        return value.to_string();
+    }
+    let lower = value.to_ascii_lowercase();
+    let bare = !value.is_empty()
+        && lower != "or"
+        && lower != "and"
+        && !value.starts_with('-')
+        && !value.starts_with('!')
+        && !value.starts_with('/')
+        && !value
+            .chars()
+            .any(|c| c.is_whitespace() || "\"\\():<>=".contains(c));
+    if bare {
+        return value.to_string();
+    }
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        if c == '"' || c == '\\' {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn colors(s: &str) -> ColorSet {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn renders_terms() {
+        assert_eq!("c:R", Query::color(Op::Colon, colors("r")).to_string());
+        assert_eq!(
+            "id<=WUB",
+            Query::identity(Op::LtEq, colors("esper")).to_string()
+        );
+        assert_eq!("mv>=3", Query::mana_value(Op::GtEq, 3).to_string());
+        assert_eq!(
+            "usd<1.50",
+            Query::price(Currency::Usd, Op::Lt, Decimal::new(150, 2)).to_string()
+        );
+        assert_eq!("r>=rare", Query::rarity(Op::GtEq, Rarity::Rare).to_string());
+        assert_eq!("banned:legacy", Query::banned(Format::Legacy).to_string());
+        assert_eq!("-is:reprint", Query::not("reprint").to_string());
+        assert_eq!("pow=\"-1\"", Query::power(Op::Eq, -1).to_string());
+    }
+
+    #[test]
+    fn quotes_values_that_need_it() {
+        assert_eq!(
+            r#"o:"draw a card""#,
+            Query::oracle("draw a card").to_string()
+        );
+        assert_eq!(
+            r#"t:"\"quoted\"""#,
+            Query::type_line("\"quoted\"").to_string()
+        );
+        assert_eq!(r#""or""#, Query::name("or").to_string());
+        assert_eq!(r#""t:goblin""#, Query::name("t:goblin").to_string());
+        assert_eq!(
+            r#"!"Fire // Ice""#,
+            Query::exact_name("Fire // Ice").to_string()
+        );
+        assert_eq!("!Shock", Query::exact_name("Shock").to_string());
+    }
+
+    #[test]
+    fn groups_only_where_needed() {
+        let goblin_or_wizard = Query::type_line("goblin") | Query::type_line("wizard");
+        let query = Query::color(Op::Colon, colors("r")) & goblin_or_wizard.clone();
+        assert_eq!("c:R (t:goblin or t:wizard)", query.to_string());
+
+        let query = !(Query::is("promo") & Query::is("digital")) | Query::set("lea");
+        assert_eq!("-(is:promo is:digital) or s:lea", query.to_string());
+
+        let nested = Query::And(vec![Query::name("a"), Query::name("b") & Query::name("c")]);
+        assert_eq!("a (b c)", nested.to_string());
+        assert_eq!("-(-a)", (!!Query::name("a")).to_string());
+    }
+
+    #[test]
+    fn chained_operators_stay_flat() {
+        let query = Query::name("a") & Query::name("b") & Query::name("c");
+        assert_eq!(
+            Query::And(vec![Query::name("a"), Query::name("b"), Query::name("c")]),
+            query
+        );
+    }
+}
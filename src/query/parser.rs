@@ -0,0 +1,460 @@
+use rust_decimal::Decimal;
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+
+use super::{Currency, Op, Query, Term};
+use types::{ColorSet, Format, Rarity};
+
+/// A query string that isn't valid Scryfall syntax
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    span: Range<usize>,
+}
+
+impl ParseError {
+    fn new<S: Into<String>>(message: S, span: Range<usize>) -> ParseError {
+        ParseError {
+            message: message.into(),
+            span,
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Byte offsets of the offending part of the query string
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} at {}..{}",
+            self.message, self.span.start, self.span.end
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parse a query string. `and` binds tighter than `or`, as on Scryfall.
+///
+/// Keywords this crate doesn't model become `Term::Other`, so any query Scryfall accepts should
+/// parse. Numeric keywords with a value that isn't a number (`pow>tou`, `mv=even`) do too.
+pub fn parse(input: &str) -> Result<Query, ParseError> {
+    let mut parser = Parser { input, pos: 0 };
+    parser.skip_whitespace();
+    if parser.at_end() {
+        return Err(ParseError::new("empty query", 0..input.len()));
+    }
+    let query = parser.or_expr()?;
+    if !parser.at_end() {
+        // `or_expr` only stops early at a `)`
+        return Err(ParseError::new("unmatched `)`", parser.pos..parser.pos + 1));
+    }
+    Ok(query)
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos == self.input.len()
+    }
+
+    /// At the end of the input or of a parenthesized group
+    fn at_group_end(&self) -> bool {
+        self.at_end() || self.peek() == Some(')')
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// The span of `word` if the input continues with it as a whole word, in any case
+    fn peek_keyword(&self, word: &str) -> Option<Range<usize>> {
+        let rest = self.rest();
+        let end = word.len();
+        let matches = rest.len() >= end
+            && rest.is_char_boundary(end)
+            && rest[..end].eq_ignore_ascii_case(word)
+            && rest[end..]
+                .chars()
+                .next()
+                .map_or(true, |c| c.is_whitespace() || c == '(' || c == ')');
+        if matches {
+            Some(self.pos..self.pos + end)
+        } else {
+            None
+        }
+    }
+
+    fn or_expr(&mut self) -> Result<Query, ParseError> {
+        let mut branches = vec![self.and_expr()?];
+        while let Some(span) = self.peek_keyword("or") {
+            self.pos = span.end;
+            self.skip_whitespace();
+            if self.at_group_end() {
+                return Err(ParseError::new("`or` needs a search after it", span));
+            }
+            branches.push(self.and_expr()?);
+        }
+        Ok(if branches.len() == 1 {
+            branches.remove(0)
+        } else {
+            Query::Or(branches)
+        })
+    }
+
+    fn and_expr(&mut self) -> Result<Query, ParseError> {
+        let mut parts = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.at_group_end() {
+                break;
+            }
+            if let Some(span) = self.peek_keyword("or") {
+                if parts.is_empty() {
+                    return Err(ParseError::new("`or` needs a search before it", span));
+                }
+                break;
+            }
+            if let Some(span) = self.peek_keyword("and") {
+                self.pos = span.end;
+                self.skip_whitespace();
+                if parts.is_empty() || self.at_group_end() || self.peek_keyword("or").is_some() {
+                    return Err(ParseError::new("`and` needs a search on both sides", span));
+                }
+                continue;
+            }
+            parts.push(self.unary()?);
+        }
+        match parts.len() {
+            0 if self.at_end() => Err(ParseError::new("expected a search", self.pos..self.pos)),
+            0 => Err(ParseError::new("unmatched `)`", self.pos..self.pos + 1)),
+            1 => Ok(parts.remove(0)),
+            _ => Ok(Query::And(parts)),
+        }
+    }
+
+    fn unary(&mut self) -> Result<Query, ParseError> {
+        let start = self.pos;
+        if self.peek() == Some('-') {
+            self.pos += 1;
+            if self.at_group_end() || self.peek().is_some_and(char::is_whitespace) {
+                return Err(ParseError::new(
+                    "`-` needs a search right after it",
+                    start..self.pos,
+                ));
+            }
+            return Ok(!self.unary()?);
+        }
+        self.atom()
+    }
+
+    fn atom(&mut self) -> Result<Query, ParseError> {
+        let start = self.pos;
+        match self.peek() {
+            Some('(') => {
+                self.pos += 1;
+                self.skip_whitespace();
+                if self.peek() == Some(')') {
+                    return Err(ParseError::new("empty parentheses", start..self.pos + 1));
+                }
+                let query = self.or_expr()?;
+                if self.peek() != Some(')') {
+                    return Err(ParseError::new("unclosed `(`", start..start + 1));
+                }
+                self.pos += 1;
+                Ok(query)
+            }
+            Some('!') => {
+                self.pos += 1;
+                Ok(Query::ExactName(self.value()?))
+            }
+            _ => self.term_or_name(),
+        }
+    }
+
+    fn term_or_name(&mut self) -> Result<Query, ParseError> {
+        let start = self.pos;
+        let keyword_len = self
+            .rest()
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or_else(|| self.rest().len());
+        if keyword_len > 0 {
+            self.pos += keyword_len;
+            if let Some(op) = self.op() {
+                let keyword = &self.input[start..start + keyword_len];
+                let value = self.value()?;
+                return Ok(term(keyword, op, value));
+            }
+            self.pos = start;
+        }
+        Ok(Query::Name(self.value()?))
+    }
+
+    fn op(&mut self) -> Option<Op> {
+        let ops = [
+            ("!=", Op::NotEq),
+            ("<=", Op::LtEq),
+            (">=", Op::GtEq),
+            (":", Op::Colon),
+            ("=", Op::Eq),
+            ("<", Op::Lt),
+            (">", Op::Gt),
+        ];
+        let &(text, op) = ops
+            .iter()
+            .find(|&&(text, _)| self.rest().starts_with(text))?;
+        self.pos += text.len();
+        Some(op)
+    }
+
+    /// A bare word, a phrase in double quotes with `\"` and `\\` escapes, or a regular
+    /// expression between slashes, which is kept as written, slashes and all
+    fn value(&mut self) -> Result<String, ParseError> {
+        let start = self.pos;
+        if self.peek() == Some('/') {
+            return self.regex();
+        }
+        if self.peek() != Some('"') {
+            let len = self
+                .rest()
+                .find(|c: char| c.is_whitespace() || c == '(' || c == ')')
+                .unwrap_or_else(|| self.rest().len());
+            if len == 0 {
+                return Err(ParseError::new("expected a value", start..start));
+            }
+            self.pos += len;
+            return Ok(self.input[start..self.pos].to_string());
+        }
+
+        let mut value = String::new();
+        let mut chars = self.rest().char_indices().skip(1);
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.pos += i + 1;
+                    return Ok(value);
+                }
+                '\\' => match chars.next() {
+                    Some((_, escaped)) => value.push(escaped),
+                    None => break,
+                },
+                c => value.push(c),
+            }
+        }
+        Err(ParseError::new(
+            "unterminated quote",
+            start..self.input.len(),
+        ))
+    }
+
+    fn regex(&mut self) -> Result<String, ParseError> {
+        let start = self.pos;
+        let mut chars = self.rest().char_indices().skip(1);
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '/' => {
+                    self.pos += i + 1;
+                    return Ok(self.input[start..self.pos].to_string());
+                }
+                // `\/` doesn't end the expression
+                '\\' => {
+                    chars.next();
+                }
+                _ => {}
+            }
+        }
+        Err(ParseError::new(
+            "unterminated regular expression",
+            start..self.input.len(),
+        ))
+    }
+}
+
+fn term(keyword: &str, op: Op, value: String) -> Query {
+    let text_op = op == Op::Colon || op == Op::Eq;
+    let number = value.parse::<Decimal>().ok();
+    // Colors that aren't a combination, like `c:m` or `c>=2`, are kept as `Other`
+    let colors = value.parse::<ColorSet>().ok();
+    let term = match (keyword.to_ascii_lowercase().as_str(), number) {
+        ("c", _) | ("color", _) if colors.is_some() => Term::Color(op, colors.unwrap()),
+        ("id", _) | ("identity", _) if colors.is_some() => Term::Identity(op, colors.unwrap()),
+        ("t", _) | ("type", _) if text_op => Term::Type(value),
+        ("o", _) | ("oracle", _) if text_op => Term::Oracle(value),
+        ("mv", Some(n)) | ("cmc", Some(n)) | ("manavalue", Some(n)) => Term::ManaValue(op, n),
+        ("pow", Some(n)) | ("power", Some(n)) => Term::Power(op, n),
+        ("tou", Some(n)) | ("toughness", Some(n)) => Term::Toughness(op, n),
+        ("s", _) | ("set", _) | ("e", _) | ("edition", _) if text_op => Term::Set(value),
+        ("r", _) | ("rarity", _) => Term::Rarity(op, rarity(&value)),
+        ("f", _) | ("format", _) | ("legal", _) if text_op => Term::Legal(format(&value)),
+        ("banned", _) if text_op => Term::Banned(format(&value)),
+        ("restricted", _) if text_op => Term::Restricted(format(&value)),
+        ("usd", Some(n)) => Term::Price(Currency::Usd, op, n),
+        ("eur", Some(n)) => Term::Price(Currency::Eur, op, n),
+        ("tix", Some(n)) => Term::Price(Currency::Tix, op, n),
+        ("is", _) if text_op => Term::Is(value),
+        ("not", _) if text_op => return !Query::is(value),
+        _ => Term::Other(keyword.to_string(), op, value),
+    };
+    Query::Term(term)
+}
+
+/// Known rarities and their initials in any case, unknown ones as written like `format`
+fn rarity(value: &str) -> Rarity {
+    match value.to_ascii_lowercase().as_str() {
+        "c" | "common" => Rarity::Common,
+        "u" | "uncommon" => Rarity::Uncommon,
+        "r" | "rare" => Rarity::Rare,
+        "m" | "mythic" => Rarity::Mythic,
+        "s" | "special" => Rarity::Special,
+        "b" | "bonus" => Rarity::Bonus,
+        _ => Rarity::Unknown(value.to_string()),
+    }
+}
+
+/// Known formats in any case, unknown ones as written so they render back the same
+fn format(value: &str) -> Format {
+    match Format::from(value.to_ascii_lowercase().as_str()) {
+        Format::Unknown(_) => Format::Unknown(value.to_string()),
+        format => format,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(input: &str) -> Query {
+        parse(input).unwrap_or_else(|e| panic!("Parse {:?}: {}", input, e))
+    }
+
+    fn error(input: &str) -> (String, Range<usize>) {
+        let e = parse(input).expect_err("Expected a parse error");
+        (e.message().to_string(), e.span())
+    }
+
+    #[test]
+    fn parses_into_the_builder_ast() {
+        let built = Query::color(Op::Colon, "r".parse().unwrap())
+            & Query::power(Op::Eq, 3)
+            & (Query::type_line("goblin") | !Query::oracle("draw a card"));
+        assert_eq!(
+            built,
+            parsed(r#"color:r POW=3 (t:goblin or -o:"draw a card")"#)
+        );
+    }
+
+    #[test]
+    fn precedence_and_keywords() {
+        let a = || Query::name("a");
+        let b = || Query::name("b");
+        let c = || Query::name("c");
+        assert_eq!((a() & b()) | c(), parsed("a and b OR c"));
+        assert_eq!(a() | (b() & c()), parsed("a or b c"));
+        assert_eq!(!Query::is("foil"), parsed("not:foil"));
+        assert_eq!(
+            Query::exact_name("Fire // Ice"),
+            parsed(r#"!"Fire // Ice""#)
+        );
+        assert_eq!(Query::rarity(Op::GtEq, Rarity::Rare), parsed("r>=r"));
+        assert_eq!(Query::name("orc"), parsed("orc"));
+    }
+
+    #[test]
+    fn regular_expressions_are_one_value() {
+        assert_eq!(Query::oracle("/(a|b) c/"), parsed("o:/(a|b) c/"));
+        assert_eq!(
+            Query::type_line(r"/^legendary\/ /") & Query::name("x"),
+            parsed(r"t:/^legendary\/ / x")
+        );
+    }
+
+    #[test]
+    fn unknown_keywords_are_kept() {
+        assert_eq!(
+            Query::Term(Term::Other("pow".to_string(), Op::Gt, "tou".to_string())),
+            parsed("pow>tou")
+        );
+        assert_eq!("art:squirrel", parsed("art:squirrel").to_string());
+        for input in &["c:m", "c:multicolor", "c>=2", "id<3"] {
+            match parsed(input) {
+                Query::Term(Term::Other(..)) => {}
+                query => panic!("{:?} parsed as {:?}", input, query),
+            }
+        }
+    }
+
+    #[test]
+    fn errors_have_spans() {
+        assert_eq!(("unclosed `(`".to_string(), 4..5), error("c:r (t:goblin"));
+        assert_eq!(("unmatched `)`".to_string(), 3..4), error("c:r) t:goblin"));
+        assert_eq!(
+            ("unterminated quote".to_string(), 2..9),
+            error(r#"o:"draw a"#)
+        );
+        assert_eq!(("empty parentheses".to_string(), 0..2), error("()"));
+        assert_eq!(
+            ("`or` needs a search after it".to_string(), 4..6),
+            error("c:r or")
+        );
+        assert_eq!(
+            ("unterminated regular expression".to_string(), 2..8),
+            error("o:/(a|b)")
+        );
+        assert_eq!(("expected a value".to_string(), 4..4), error("pow="));
+        assert_eq!(("empty query".to_string(), 0..2), error("  "));
+    }
+
+    #[test]
+    fn round_trips() {
+        for input in &[
+            r#"c:r pow=3"#,
+            r#"id<=esper t:"legendary creature" -(is:promo or s:lea)"#,
+            r#"o:"\"quoted\"" "or" !Shock usd<=0.50 f:commander banned:modern"#,
+            r#"a (b c) or -(-d) mv>=2.5 tou!=1 r:m eur>1 tix<2 art:squirrel"#,
+            r#"o:/(a|b)/ t:/"\/ x/ (ft:/\(/)"#,
+        ] {
+            let query = parsed(input);
+            assert_eq!(query, parsed(&query.to_string()), "{}", input);
+        }
+
+        for query in &[
+            Query::legal(Format::Unknown("Foo".to_string())),
+            Query::rarity(Op::Colon, Rarity::Unknown("Purple".to_string())),
+            Query::name("/foo"),
+            Query::name("/"),
+            Query::name("/a/b/"),
+            Query::exact_name(r"/a\/"),
+            Query::oracle(r"/a\/b/"),
+        ] {
+            assert_eq!(*query, parsed(&query.to_string()));
+        }
+        assert_eq!(
+            r#""/foo" "/a\\/""#,
+            Query::name("/foo").and(Query::name(r"/a\/")).to_string()
+        );
+        assert_eq!(r"o:/a\/b/", Query::oracle(r"/a\/b/").to_string());
+        assert_eq!(Query::legal(Format::Modern), parsed("f:Modern"));
+    }
+}
@@ -1,5 +1,6 @@
 use super::types;
 use super::types::*;
+use query::Query;
 use ScryfallRequest;
 
 #[derive(Debug)]
@@ -9,6 +10,7 @@ pub struct Sets;
 pub struct Set(pub SetCode);
 
 #[derive(Debug)]
+#[deprecated(note = "Scryfall no longer pages through every card; download BulkData instead")]
 pub struct Cards; // The entire database
 
 #[derive(Debug)]
@@ -20,42 +22,113 @@ pub struct CardNamed(pub String, pub Exact);
 #[derive(Debug)]
 pub struct CardAutoComplete(pub String);
 
+#[derive(Debug)]
 pub struct CardRandom;
-pub struct CardMultiverse(MultiverseId);
-pub struct CardMtgo(MtgoId);
-pub struct CardInSet(SetCode, SetNumber);
-pub struct CardScryfall(ScryfallId);
 
-pub struct RulingsMultiverse(MultiverseId);
-pub struct RulingsMtgo(MtgoId);
-pub struct RulingsInSet(SetCode, SetNumber);
-pub struct RulingsScryfall(ScryfallId);
+#[derive(Debug)]
+pub struct CardMultiverse(pub MultiverseId);
+
+#[derive(Debug)]
+pub struct CardMtgo(pub MtgoId);
+
+/// A card by set code and collector number, optionally in a specific language (`"ja"`, `"de"`, ...)
+#[derive(Debug)]
+pub struct CardInSet(pub SetCode, pub CollectorNumber, pub Option<String>);
+
+#[derive(Debug)]
+pub struct CardScryfall(pub ScryfallId);
+
+/// The image of a card, straight from the API instead of its `image_uris`
+#[derive(Debug)]
+pub struct CardImage(pub ScryfallId, pub ImageVersion, pub ImageFace);
+
+#[derive(Debug)]
+pub struct RulingsMultiverse(pub MultiverseId);
+
+#[derive(Debug)]
+pub struct RulingsMtgo(pub MtgoId);
+
+#[derive(Debug)]
+pub struct RulingsInSet(pub SetCode, pub CollectorNumber);
 
+#[derive(Debug)]
+pub struct RulingsScryfall(pub ScryfallId);
+
+#[derive(Debug)]
 pub struct CardSymbols;
-pub struct ParseMana(ManaCost);
+
+/// The bulk data files Scryfall publishes every day. Download one with
+/// `ScryfallApi::download_bulk_data`.
+#[derive(Debug)]
+pub struct BulkData;
+
+/// Ask Scryfall to normalize and describe a mana cost like `"RGX"` or `"{2}{U/P}"`
+#[derive(Debug)]
+pub struct ParseMana(pub ManaCost);
 
 // Bulk queries
+#[derive(Debug)]
 pub struct CatalogCardNames;
+#[derive(Debug)]
+pub struct CatalogArtistNames;
+#[derive(Debug)]
 pub struct CatalogWordBank;
+#[derive(Debug)]
 pub struct CatalogCreatureTypes;
+#[derive(Debug)]
 pub struct CatalogPlaneswalkerTypes;
+#[derive(Debug)]
 pub struct CatalogLandTypes;
+#[derive(Debug)]
+pub struct CatalogArtifactTypes;
+#[derive(Debug)]
 pub struct CatalogEnchantmentTypes;
+#[derive(Debug)]
 pub struct CatalogSpellTypes;
+#[derive(Debug)]
 pub struct CatalogPowers;
+#[derive(Debug)]
 pub struct CatalogToughnesses;
+#[derive(Debug)]
 pub struct CatalogLoyalties;
+#[derive(Debug)]
 pub struct CatalogWatermarks;
+#[derive(Debug)]
+pub struct CatalogKeywordAbilities;
+#[derive(Debug)]
+pub struct CatalogKeywordActions;
+#[derive(Debug)]
+pub struct CatalogAbilityWords;
+#[derive(Debug)]
+pub struct CatalogCardTypes;
+#[derive(Debug)]
+pub struct CatalogSupertypes;
+
+/// Any catalog, picked at runtime. `CatalogKind::all()` lists every one of them.
+#[derive(Debug)]
+pub struct Catalog(pub CatalogKind);
 
 //////////////////////
 //  Helper Structs  //
 //////////////////////
 
-#[derive(Debug)]
+/// Everything on /cards/search besides the query itself.
+///
+/// Start from `Default::default()` and chain the setters:
+/// `CardSearchOptions::default().unique(SearchUniquenessMode::Prints).order(SearchOrdering::USD)`
+#[derive(Debug, Clone, PartialEq)]
 pub struct CardSearchOptions {
-    uniqueness: SearchUniquenessMode,
-    ordering: SearchOrdering,
-    ordering_direction: SearchOrderingDirection,
+    pub uniqueness: SearchUniquenessMode,
+    pub ordering: SearchOrdering,
+    pub ordering_direction: SearchOrderingDirection,
+    /// Include extra cards (tokens, planes, etc) in the results
+    pub include_extras: bool,
+    /// Include cards in every language supported by Scryfall
+    pub include_multilingual: bool,
+    /// Include rare card variants
+    pub include_variations: bool,
+    /// The page number to return, starting at 1
+    pub page: Option<u32>,
 }
 
 impl Default for CardSearchOptions {
@@ -64,10 +137,73 @@ impl Default for CardSearchOptions {
             uniqueness: SearchUniquenessMode::Cards,
             ordering: SearchOrdering::Name,
             ordering_direction: SearchOrderingDirection::Auto,
+            include_extras: false,
+            include_multilingual: false,
+            include_variations: false,
+            page: None,
         }
     }
 }
 
+impl CardSearchOptions {
+    pub fn unique(mut self, uniqueness: SearchUniquenessMode) -> CardSearchOptions {
+        self.uniqueness = uniqueness;
+        self
+    }
+
+    pub fn order(mut self, ordering: SearchOrdering) -> CardSearchOptions {
+        self.ordering = ordering;
+        self
+    }
+
+    pub fn direction(mut self, direction: SearchOrderingDirection) -> CardSearchOptions {
+        self.ordering_direction = direction;
+        self
+    }
+
+    pub fn include_extras(mut self, include: bool) -> CardSearchOptions {
+        self.include_extras = include;
+        self
+    }
+
+    pub fn include_multilingual(mut self, include: bool) -> CardSearchOptions {
+        self.include_multilingual = include;
+        self
+    }
+
+    pub fn include_variations(mut self, include: bool) -> CardSearchOptions {
+        self.include_variations = include;
+        self
+    }
+
+    pub fn page(mut self, page: u32) -> CardSearchOptions {
+        self.page = Some(page);
+        self
+    }
+
+    /// The query string parameters for these options, in the order Scryfall documents them
+    fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = vec![
+            ("unique", self.uniqueness.as_str().to_string()),
+            ("order", self.ordering.as_str().to_string()),
+            ("dir", self.ordering_direction.as_str().to_string()),
+        ];
+        if self.include_extras {
+            pairs.push(("include_extras", "true".to_string()));
+        }
+        if self.include_multilingual {
+            pairs.push(("include_multilingual", "true".to_string()));
+        }
+        if self.include_variations {
+            pairs.push(("include_variations", "true".to_string()));
+        }
+        if let Some(page) = self.page {
+            pairs.push(("page", page.to_string()));
+        }
+        pairs
+    }
+}
+
 #[derive(Debug)]
 pub struct CardSearchQuery(String);
 
@@ -77,7 +213,48 @@ pub enum Exact {
     Fuzzy,
 }
 
-#[derive(Debug)]
+/// https://scryfall.com/docs/api/catalogs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CatalogKind {
+    /// All nontoken English card names
+    CardNames,
+    /// All canonical artist names
+    ArtistNames,
+    /// All English words, of length 2 or more, that could appear in a card name
+    WordBank,
+    /// All creature types
+    CreatureTypes,
+    /// All Planeswalker types
+    PlaneswalkerTypes,
+    /// All Land types
+    LandTypes,
+    /// All artifact types
+    ArtifactTypes,
+    /// All enchantment types
+    EnchantmentTypes,
+    /// All spell types
+    SpellTypes,
+    /// All possible values for a creature or vehicle’s power
+    Powers,
+    /// All possible values for a creature or vehicle’s toughness
+    Toughnesses,
+    /// All possible values for a Planeswalker’s loyalty
+    Loyalties,
+    /// All card watermarks
+    Watermarks,
+    /// All keyword abilities
+    KeywordAbilities,
+    /// All keyword actions
+    KeywordActions,
+    /// All ability words
+    AbilityWords,
+    /// All card types
+    CardTypes,
+    /// All supertypes
+    Supertypes,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SearchUniquenessMode {
     /// One copy of each card name (no matter how many printings)
     Cards,
@@ -87,7 +264,7 @@ pub enum SearchUniquenessMode {
     Prints,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SearchOrdering {
     /// Sort cards by name, A → Z (default)
     Name,
@@ -115,7 +292,7 @@ pub enum SearchOrdering {
     Artist,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SearchOrderingDirection {
     /// Scryfall will automatically choose the most inuitive direction to sort
     Auto,
@@ -125,6 +302,98 @@ pub enum SearchOrderingDirection {
     Desc,
 }
 
+impl CatalogKind {
+    pub fn all() -> &'static [CatalogKind] {
+        &[
+            CatalogKind::CardNames,
+            CatalogKind::ArtistNames,
+            CatalogKind::WordBank,
+            CatalogKind::CreatureTypes,
+            CatalogKind::PlaneswalkerTypes,
+            CatalogKind::LandTypes,
+            CatalogKind::ArtifactTypes,
+            CatalogKind::EnchantmentTypes,
+            CatalogKind::SpellTypes,
+            CatalogKind::Powers,
+            CatalogKind::Toughnesses,
+            CatalogKind::Loyalties,
+            CatalogKind::Watermarks,
+            CatalogKind::KeywordAbilities,
+            CatalogKind::KeywordActions,
+            CatalogKind::AbilityWords,
+            CatalogKind::CardTypes,
+            CatalogKind::Supertypes,
+        ]
+    }
+
+    /// The last segment of the catalog's URI, like `card-names`
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            CatalogKind::CardNames => "card-names",
+            CatalogKind::ArtistNames => "artist-names",
+            CatalogKind::WordBank => "word-bank",
+            CatalogKind::CreatureTypes => "creature-types",
+            CatalogKind::PlaneswalkerTypes => "planeswalker-types",
+            CatalogKind::LandTypes => "land-types",
+            CatalogKind::ArtifactTypes => "artifact-types",
+            CatalogKind::EnchantmentTypes => "enchantment-types",
+            CatalogKind::SpellTypes => "spell-types",
+            CatalogKind::Powers => "powers",
+            CatalogKind::Toughnesses => "toughnesses",
+            CatalogKind::Loyalties => "loyalties",
+            CatalogKind::Watermarks => "watermarks",
+            CatalogKind::KeywordAbilities => "keyword-abilities",
+            CatalogKind::KeywordActions => "keyword-actions",
+            CatalogKind::AbilityWords => "ability-words",
+            CatalogKind::CardTypes => "card-types",
+            CatalogKind::Supertypes => "supertypes",
+        }
+    }
+
+    fn path(&self) -> String {
+        format!("/catalog/{}", self.as_str())
+    }
+}
+
+impl SearchUniquenessMode {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            SearchUniquenessMode::Cards => "cards",
+            SearchUniquenessMode::Arts => "art",
+            SearchUniquenessMode::Prints => "prints",
+        }
+    }
+}
+
+impl SearchOrdering {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            SearchOrdering::Name => "name",
+            SearchOrdering::Set => "set",
+            SearchOrdering::Rarity => "rarity",
+            SearchOrdering::Color => "color",
+            SearchOrdering::USD => "usd",
+            SearchOrdering::TIX => "tix",
+            SearchOrdering::Eur => "eur",
+            SearchOrdering::CMC => "cmc",
+            SearchOrdering::Power => "power",
+            SearchOrdering::Toughness => "toughness",
+            SearchOrdering::Edhrec => "edhrec",
+            SearchOrdering::Artist => "artist",
+        }
+    }
+}
+
+impl SearchOrderingDirection {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            SearchOrderingDirection::Auto => "auto",
+            SearchOrderingDirection::Asc => "asc",
+            SearchOrderingDirection::Desc => "desc",
+        }
+    }
+}
+
 /////////////
 //  Impls  //
 /////////////
@@ -144,6 +413,7 @@ impl ScryfallRequest for Set {
     }
 }
 
+#[allow(deprecated)]
 impl ScryfallRequest for Cards {
     type Response = types::ListObject<types::Card>;
 
@@ -176,18 +446,342 @@ impl ScryfallRequest for CardSearch {
     type Response = types::ListObject<Card>;
 
     fn path(&self) -> String {
-        use url::percent_encoding::{utf8_percent_encode, DEFAULT_ENCODE_SET};
+        use url::form_urlencoded::Serializer;
+
+        let mut query = Serializer::new(String::new());
+        query.append_pair("q", &(self.0).0);
+        for (key, value) in self.1.query_pairs() {
+            query.append_pair(key, &value);
+        }
+        format!("/cards/search?{}", query.finish())
+    }
+}
+
+impl ScryfallRequest for CardRandom {
+    type Response = types::Card;
+
+    fn path(&self) -> String {
+        "/cards/random".into()
+    }
+}
+
+impl ScryfallRequest for CardMultiverse {
+    type Response = types::Card;
+
+    fn path(&self) -> String {
+        format!("/cards/multiverse/{}", self.0)
+    }
+}
+
+impl ScryfallRequest for CardMtgo {
+    type Response = types::Card;
+
+    fn path(&self) -> String {
+        format!("/cards/mtgo/{}", self.0)
+    }
+}
+
+impl CardInSet {
+    /// The English printing of `number` in `set`
+    pub fn new<N: Into<CollectorNumber>>(set: SetCode, number: N) -> CardInSet {
+        CardInSet(set, number.into(), None)
+    }
+}
+
+impl ScryfallRequest for CardInSet {
+    type Response = types::Card;
+
+    fn path(&self) -> String {
+        match self.2 {
+            Some(ref lang) => format!("/cards/{}/{}/{}", self.0, path_segment(&self.1), lang),
+            None => format!("/cards/{}/{}", self.0, path_segment(&self.1)),
+        }
+    }
+}
 
-        let search = &(self.0).0;
-        let encoded_search = utf8_percent_encode(&search, DEFAULT_ENCODE_SET).to_string();
+impl ScryfallRequest for CardScryfall {
+    type Response = types::Card;
 
-        println!("Encoded Search is: {}", encoded_search);
-        format!("/cards/search?q={}", encoded_search)
+    fn path(&self) -> String {
+        format!("/cards/{}", self.0)
     }
 }
 
+impl ScryfallRequest for CardImage {
+    type Response = types::Image;
+
+    fn path(&self) -> String {
+        // The front is what Scryfall returns without a face parameter
+        match self.2 {
+            ImageFace::Front => {
+                format!("/cards/{}?format=image&version={}", self.0, self.1.as_str())
+            }
+            ImageFace::Back => format!(
+                "/cards/{}?format=image&version={}&face={}",
+                self.0,
+                self.1.as_str(),
+                self.2.as_str()
+            ),
+        }
+    }
+}
+
+impl ScryfallRequest for RulingsMultiverse {
+    type Response = types::ListObject<Ruling>;
+
+    fn path(&self) -> String {
+        format!("/cards/multiverse/{}/rulings", self.0)
+    }
+}
+
+impl ScryfallRequest for RulingsMtgo {
+    type Response = types::ListObject<Ruling>;
+
+    fn path(&self) -> String {
+        format!("/cards/mtgo/{}/rulings", self.0)
+    }
+}
+
+impl ScryfallRequest for RulingsInSet {
+    type Response = types::ListObject<Ruling>;
+
+    fn path(&self) -> String {
+        format!("/cards/{}/{}/rulings", self.0, path_segment(&self.1))
+    }
+}
+
+impl ScryfallRequest for RulingsScryfall {
+    type Response = types::ListObject<Ruling>;
+
+    fn path(&self) -> String {
+        format!("/cards/{}/rulings", self.0)
+    }
+}
+
+impl ScryfallRequest for CardSymbols {
+    type Response = types::ListObject<CardSymbol>;
+
+    fn path(&self) -> String {
+        "/symbology".into()
+    }
+}
+
+impl ScryfallRequest for BulkData {
+    type Response = types::ListObject<BulkDataItem>;
+
+    fn path(&self) -> String {
+        "/bulk-data".into()
+    }
+}
+
+impl ScryfallRequest for ParseMana {
+    type Response = types::ManaCostParse;
+
+    fn path(&self) -> String {
+        use url::form_urlencoded::Serializer;
+
+        let query = Serializer::new(String::new())
+            .append_pair("cost", &self.0)
+            .finish();
+        format!("/symbology/parse-mana?{}", query)
+    }
+}
+
+impl ScryfallRequest for Catalog {
+    type Response = types::Catalog;
+
+    fn path(&self) -> String {
+        self.0.path()
+    }
+}
+
+macro_rules! catalog_request {
+    ($request:ident, $kind:ident) => {
+        impl ScryfallRequest for $request {
+            type Response = types::Catalog;
+
+            fn path(&self) -> String {
+                CatalogKind::$kind.path()
+            }
+        }
+    };
+}
+
+catalog_request!(CatalogCardNames, CardNames);
+catalog_request!(CatalogArtistNames, ArtistNames);
+catalog_request!(CatalogWordBank, WordBank);
+catalog_request!(CatalogCreatureTypes, CreatureTypes);
+catalog_request!(CatalogPlaneswalkerTypes, PlaneswalkerTypes);
+catalog_request!(CatalogLandTypes, LandTypes);
+catalog_request!(CatalogArtifactTypes, ArtifactTypes);
+catalog_request!(CatalogEnchantmentTypes, EnchantmentTypes);
+catalog_request!(CatalogSpellTypes, SpellTypes);
+catalog_request!(CatalogPowers, Powers);
+catalog_request!(CatalogToughnesses, Toughnesses);
+catalog_request!(CatalogLoyalties, Loyalties);
+catalog_request!(CatalogWatermarks, Watermarks);
+catalog_request!(CatalogKeywordAbilities, KeywordAbilities);
+catalog_request!(CatalogKeywordActions, KeywordActions);
+catalog_request!(CatalogAbilityWords, AbilityWords);
+catalog_request!(CatalogCardTypes, CardTypes);
+catalog_request!(CatalogSupertypes, Supertypes);
+
 impl From<String> for CardSearchQuery {
     fn from(s: String) -> CardSearchQuery {
         CardSearchQuery(s)
     }
 }
+
+impl From<Query> for CardSearchQuery {
+    fn from(query: Query) -> CardSearchQuery {
+        CardSearchQuery(query.to_string())
+    }
+}
+
+/// Percent-encode a collector number for use in a URL path, since some contain a `★`
+fn path_segment(number: &CollectorNumber) -> String {
+    number
+        .as_str()
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn card_lookup_paths() {
+        assert_eq!("/cards/random", CardRandom.path());
+        assert_eq!(
+            "/cards/multiverse/442054",
+            CardMultiverse(MultiverseId(442054)).path()
+        );
+        assert_eq!("/cards/mtgo/67044", CardMtgo(MtgoId(67044)).path());
+        assert_eq!(
+            "/cards/a25/65",
+            CardInSet::new("a25".to_string(), 65).path()
+        );
+        assert_eq!(
+            "/cards/a25/65/ja",
+            CardInSet("a25".to_string(), 65.into(), Some("ja".to_string())).path()
+        );
+        assert_eq!(
+            "/cards/8cae1a42-052e-4110-9afc-d3ec83b7c8a9",
+            CardScryfall("8cae1a42-052e-4110-9afc-d3ec83b7c8a9".parse().unwrap()).path()
+        );
+    }
+
+    #[test]
+    fn card_image_paths() {
+        let id: ScryfallId = "11bf83bb-c95b-4b4f-9a56-ce7a1816307a".parse().unwrap();
+        assert_eq!(
+            "/cards/11bf83bb-c95b-4b4f-9a56-ce7a1816307a?format=image&version=art_crop",
+            CardImage(id, ImageVersion::ArtCrop, ImageFace::Front).path()
+        );
+        assert_eq!(
+            "/cards/11bf83bb-c95b-4b4f-9a56-ce7a1816307a?format=image&version=large&face=back",
+            CardImage(id, ImageVersion::Large, ImageFace::Back).path()
+        );
+    }
+
+    #[test]
+    fn rulings_paths() {
+        assert_eq!(
+            "/cards/multiverse/3255/rulings",
+            RulingsMultiverse(MultiverseId(3255)).path()
+        );
+        assert_eq!(
+            "/cards/mtgo/57934/rulings",
+            RulingsMtgo(MtgoId(57934)).path()
+        );
+        assert_eq!(
+            "/cards/ima/65/rulings",
+            RulingsInSet("ima".to_string(), 65.into()).path()
+        );
+        assert_eq!(
+            "/cards/pgpx/2018%E2%98%85/rulings",
+            RulingsInSet("pgpx".to_string(), "2018★".into()).path()
+        );
+        assert_eq!(
+            "/cards/f2b9983e-20d4-4d12-9e2c-ec6d9a345787/rulings",
+            RulingsScryfall("f2b9983e-20d4-4d12-9e2c-ec6d9a345787".parse().unwrap()).path()
+        );
+    }
+
+    #[test]
+    fn bulk_data_path() {
+        assert_eq!("/bulk-data", BulkData.path());
+    }
+
+    #[test]
+    fn parse_mana_path() {
+        assert_eq!(
+            "/symbology/parse-mana?cost=%7BX%7D%7BR%2FP%7D+G",
+            ParseMana("{X}{R/P} G".to_string()).path()
+        );
+    }
+
+    #[test]
+    fn catalog_paths() {
+        assert_eq!("/catalog/card-names", CatalogCardNames.path());
+        assert_eq!("/catalog/word-bank", CatalogWordBank.path());
+        assert_eq!("/catalog/supertypes", CatalogSupertypes.path());
+        assert_eq!(
+            "/catalog/keyword-abilities",
+            Catalog(CatalogKind::KeywordAbilities).path()
+        );
+
+        let mut paths: Vec<String> = CatalogKind::all()
+            .iter()
+            .map(|kind| Catalog(*kind).path())
+            .collect();
+        paths.sort();
+        paths.dedup();
+        assert_eq!(18, paths.len());
+    }
+
+    #[test]
+    fn card_search_default_path() {
+        let req = CardSearch("c:r pow=3".to_string().into(), Default::default());
+        assert_eq!(
+            "/cards/search?q=c%3Ar+pow%3D3&unique=cards&order=name&dir=auto",
+            req.path()
+        );
+    }
+
+    #[test]
+    fn card_search_query_path() {
+        use query::Op;
+        let query = Query::color(Op::Colon, "r".parse().unwrap()) & Query::oracle("draw a card");
+        let req = CardSearch(query.into(), Default::default());
+        assert_eq!(
+            "/cards/search?q=c%3AR+o%3A%22draw+a+card%22&unique=cards&order=name&dir=auto",
+            req.path()
+        );
+    }
+
+    #[test]
+    fn card_search_options_path() {
+        let options = CardSearchOptions::default()
+            .unique(SearchUniquenessMode::Prints)
+            .order(SearchOrdering::USD)
+            .direction(SearchOrderingDirection::Desc)
+            .include_extras(true)
+            .include_multilingual(true)
+            .include_variations(true)
+            .page(3);
+        let req = CardSearch("t:goblin & t:wizard".to_string().into(), options);
+        assert_eq!(
+            "/cards/search?q=t%3Agoblin+%26+t%3Awizard&unique=prints&order=usd&dir=desc\
+             &include_extras=true&include_multilingual=true&include_variations=true&page=3",
+            req.path()
+        );
+    }
+}
@@ -1,15 +1,63 @@
-use super::ScryfallResponse;
+use super::{ScryfallError, ScryfallResponse};
+pub use collector_number::CollectorNumber;
+pub use color::{Color, ColorSet, ParseColorSetError, ALL_COLORS};
+use rust_decimal::Decimal;
 use serde_json;
+use std::fmt;
 
 extern crate chrono;
+#[allow(clippy::upper_case_acronyms)]
 type URI = String;
 
 pub type ManaCost = String;
 pub type SetCode = String;
-pub type SetNumber = i64; // Collectors number in set. TODO: Way too big, what is the real range?
-pub type MultiverseId = i64; // TODO: Way too big, what is the real range?
-pub type MtgoId = i64; // TODO: Way too big, what is the real range?
-pub type ScryfallId = String; // TODO: Way too big, what is the real range?
+
+uuid_id! {
+    /// One printing of a card, the `id` of a card object
+    pub struct ScryfallId;
+}
+
+uuid_id! {
+    /// A card's Oracle identity, shared by every printing of it
+    pub struct OracleId;
+}
+
+uuid_id! {
+    /// A piece of card art, shared by every printing that uses it
+    pub struct IllustrationId;
+}
+
+/// A card's id on Gatherer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct MultiverseId(pub u32);
+
+/// A card's id on Magic Online, also known as its Catalog ID
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct MtgoId(pub u32);
+
+impl fmt::Display for MultiverseId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for MtgoId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// A string that isn't a UUID was parsed as a `ScryfallId`, `OracleId` or `IllustrationId`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseIdError(String);
+
+impl fmt::Display for ParseIdError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "not a UUID: {:?}", self.0)
+    }
+}
+
+impl ::std::error::Error for ParseIdError {}
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Set {
@@ -22,8 +70,9 @@ pub struct Set {
     /// The English name of the set.
     pub name: String,
     /// A computer-readable classification for this set. See below.
-    pub set_type: String,
-    pub released_at: Option<String>, // Option<chrono::DateTime<chrono::Utc>>,
+    pub set_type: SetType,
+    /// The date the set was released (in GMT-8 Pacific time). Not all sets have a known release date.
+    pub released_at: Option<chrono::NaiveDate>,
     pub block_code: Option<String>,
     /// Nullable The block or group name code for this set, if any.
     pub block: Option<String>,
@@ -45,20 +94,74 @@ pub struct Set {
     pub scryfall_uri: URI,
 }
 
+string_enum! {
+    /// https://scryfall.com/docs/api/sets
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum SetType {
+        /// A yearly Magic core set (Tenth Edition, etc)
+        Core => "core",
+        /// A rotational expansion set in a block (Zendikar, etc)
+        Expansion => "expansion",
+        /// A reprint set that contains no new cards (Modern Masters, etc)
+        Masters => "masters",
+        /// An Arena set designed for Alchemy
+        Alchemy => "alchemy",
+        /// Masterpiece Series premium foil cards
+        Masterpiece => "masterpiece",
+        /// A Commander-oriented gift set
+        Arsenal => "arsenal",
+        /// From the Vault gift sets
+        FromTheVault => "from_the_vault",
+        /// Spellbook series gift sets
+        Spellbook => "spellbook",
+        /// Premium Deck Series decks
+        PremiumDeck => "premium_deck",
+        /// Duel Decks
+        DuelDeck => "duel_deck",
+        /// Special draft sets, like Conspiracy and Battlebond
+        DraftInnovation => "draft_innovation",
+        /// What `DraftInnovation` sets were called before Scryfall renamed the type
+        Conspiracy => "conspiracy",
+        /// Magic Online treasure chest prize sets
+        TreasureChest => "treasure_chest",
+        /// Commander preconstructed decks
+        Commander => "commander",
+        /// Planechase sets
+        Planechase => "planechase",
+        /// Archenemy sets
+        Archenemy => "archenemy",
+        /// Vanguard card sets
+        Vanguard => "vanguard",
+        /// A funny un-set or set with funny promos (Unglued, Happy Holidays, etc)
+        Funny => "funny",
+        /// A starter/introductory set (Portal, etc)
+        Starter => "starter",
+        /// A gift box set
+        Box => "box",
+        /// A set that contains purely promotional cards
+        Promo => "promo",
+        /// A set made up of tokens and emblems.
+        Token => "token",
+        /// A set made up of gold-bordered, oversize, or trophy cards that are not legal
+        Memorabilia => "memorabilia",
+        /// A set that contains minigame card inserts from booster packs
+        Minigame => "minigame",
+    }
+}
+
 use std::collections::HashMap;
-type Color = String;
-type Colors = Vec<Color>;
-type Legalities = HashMap<String, String>;
+type Legalities = HashMap<Format, Legality>;
 type Images = HashMap<String, URI>;
 
 #[derive(Debug, Serialize, Deserialize)]
+#[allow(clippy::tabs_in_doc_comments)]
 pub struct Card {
     /// 	UUID		A unique ID for this card in Scryfall’s database.
-    pub id: String,
-    /// 	UUID		A unique ID for this card’s oracle identity. This value is consistent across reprinted card editions, and unique among different cards with the same name (tokens, Unstable variants, etc).
-    pub oracle_id: String,
+    pub id: ScryfallId,
+    /// 	UUID		A unique ID for this card’s oracle identity. This value is consistent across reprinted card editions, and unique among different cards with the same name (tokens, Unstable variants, etc). Reversible cards only have one per face.
+    pub oracle_id: Option<OracleId>,
     /// 	Array	Nullable This card’s multiverse IDs on Gatherer, if any, as an array of integers. Note that Scryfall includes many promo cards, tokens, and other esoteric objects that do not have these identifiers.
-    pub multiverse_ids: Option<Vec<u64>>,
+    pub multiverse_ids: Option<Vec<MultiverseId>>,
     /// 	Integer	Nullable This card’s Magic Online ID (also known as the Catalog ID), if any. A large percentage of cards are not available on Magic Online and do not have this ID.
     pub mtgo_id: Option<MtgoId>,
     /// 	Integer	Nullable This card’s foil Magic Online ID (also known as the Catalog ID), if any. A large percentage of cards are not available on Magic Online and do not have this ID.
@@ -74,7 +177,7 @@ pub struct Card {
     ///String		The name of this card. If this card has multiple faces, this field will contain both names separated by ␣//␣.
     pub name: String,
     ///String		A computer-readable designation for this card’s layout. See the layout article.
-    pub layout: String,
+    pub layout: Layout,
     ///Decimal		The card’s converted mana cost. Note that some funny cards have fractional mana costs.
     pub cmc: f64,
     ///String		The type line of this card.
@@ -94,17 +197,17 @@ pub struct Card {
     ///String	Nullable This card’s hand modifier, if it is Vanguard card. This value will contain a delta, such as -1.
     pub hand_modifier: Option<String>,
     ///Colors		This card’s colors.
-    pub colors: Option<Colors>,
+    pub colors: Option<ColorSet>,
     ///Colors	Nullable The colors in this card’s color indicator, if any. A null value for this field indicates the card does not have one.
-    pub color_indicator: Option<Colors>,
+    pub color_indicator: Option<ColorSet>,
     ///Colors		This card’s color identity.
-    pub color_identity: Colors,
+    pub color_identity: ColorSet,
 
-    ///Array	Nullable If this card is closely related to other cards, this property will be an array with.
-    // pub all_parts: Option<Vec<RelatedCard>>,
+    ///Array	Nullable If this card is closely related to other cards, this property will be an array with Related Card Objects.
+    pub all_parts: Option<Vec<RelatedCard>>,
 
     ///Array	Nullable An array of Card Face objects, if this card is multifaced.
-    // pub card_faces: Option<Vec<String>>,
+    pub card_faces: Option<Vec<CardFace>>,
 
     ///Object		An object describing the legality of this card.
     pub legalities: Legalities,
@@ -117,7 +220,7 @@ pub struct Card {
     ///String		This card’s full set name.
     pub set_name: String,
     ///String		This card’s collector number. Note that collector numbers can contain non-numeric characters, such as letters or ★.
-    pub collector_number: String,
+    pub collector_number: CollectorNumber,
     ///URI		A link to where you can begin paginating this card’s set on the Scryfall API.
     pub set_search_uri: URI,
     ///URI		A link to this card’s set on Scryfall’s website.
@@ -130,82 +233,649 @@ pub struct Card {
     pub reprint: bool,
     ///Boolean		True if this is a digital card on Magic Online.
     pub digital: bool,
-    ///String		This card’s rarity. One of common, uncommon, rare, or mythic.
-    pub rarity: String,
+    ///String		This card’s rarity. One of common, uncommon, rare, special, mythic, or bonus.
+    pub rarity: Rarity,
     ///String	Nullable The flavor text, if any.
     pub flavor_text: Option<String>,
     ///String	Nullable The name of the illustrator of this card. Newly spoiled cards may not have this field yet.
     pub artist: Option<String>,
     ///UUID	Nullable A unique identifier for the card artwork that remains consistent across reprints. Newly spoiled cards may not have this field yet.
-    pub illustration_id: Option<String>,
+    pub illustration_id: Option<IllustrationId>,
     ///String		This card’s frame layout. See.
-    pub frame: String,
+    pub frame: Frame,
+    /// Array Nullable This card’s frame effects, if any.
+    pub frame_effects: Option<Vec<FrameEffect>>,
     ///Boolean		True if this card’s artwork is larger than normal.
     pub full_art: bool,
     ///String	Nullable This card’s watermark, if any.
     pub watermark: Option<String>,
     ///String		This card’s border color: black, borderless, gold, silver, or white.
-    pub border_color: String,
+    pub border_color: BorderColor,
     ///Integer	Nullable This card’s story spotlight number, if any.
     pub story_spotlight_number: Option<u64>,
     ///URI	Nullable A URL to this cards’s story article, if any.
     pub story_spotlight_uri: Option<URI>,
-    ///Boolean		True if this card is timeshifted.
+    ///Boolean		True if this card is timeshifted. Only in older responses.
+    #[serde(default)]
     pub timeshifted: bool,
-    ///Boolean		Ture if this card is colorshifted.
+    ///Boolean		True if this card is colorshifted. Only in older responses, newer ones use
+    /// `FrameEffect::Colorshifted`.
+    #[serde(default)]
     pub colorshifted: bool,
-    ///Boolean		True if this card is from the future.
+    ///Boolean		True if this card is from the future. Only in older responses, newer ones use
+    /// `Frame::Future`.
+    #[serde(default)]
     pub futureshifted: bool,
+    /// Object Nullable Daily price information for this card. Use `Card::prices`, which also
+    /// understands the older flat `usd`/`eur`/`tix` fields.
+    prices: Option<Prices>,
+    /// Decimal Nullable Legacy flat price in US dollars, superseded by `prices.usd`
+    usd: Option<Decimal>,
+    /// Decimal Nullable Legacy flat price in Euros, superseded by `prices.eur`
+    eur: Option<Decimal>,
+    /// Decimal Nullable Legacy flat price in MTGO tickets, superseded by `prices.tix`
+    tix: Option<Decimal>,
+    /// Object Nullable Links to this card on other Magic resources.
+    pub related_uris: Option<RelatedUris>,
+    /// Object Nullable Links to buy this card from online retailers.
+    pub purchase_uris: Option<PurchaseUris>,
 }
 
-/// TODO
-struct CardFace {
-// CardFaces
-// name: String, //String		The name of this particular face.
-// type_line: String, //String		The type line of this particular face.
-// oracle_text: Option<String>, //String	Nullable The Oracle text for this face, if any.
-// mana_cost: String, //String		The mana cost for this face. This value will be any empty string "" if the cost is absent. Remember that per the game rules, a missing mana cost and a mana cost of {0} are different values.
-// colors: Colors, //Colors		This face’s colors.
-// color_indicator //Colors	Nullable The colors in this face’s color indicator, if any.
-// power //String	Nullable This face’s power, if any. Note that some cards have powers that are not numeric, such as *.
-// toughness //String	Nullable This face’s toughness, if any.
-// loyalty //String	Nullable This face’s loyalty, if any.
-// flavor_text //String	Nullable The flavor text printed on this face, if any.
-// illustration_id //UUID	Nullable A unique identifier for the card face artwork that remains consistent across reprints. Newly spoiled cards may not have this field yet.
-// image_uris //Object	Nullable An object providing URIs to imagery for this face, if this is a double-sided card. If this card is not double-sided, then the image_uris property will be part of the parent object instead.
+/// What a card sells for. Scryfall leaves out prices it has no recent data for.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Prices {
+    pub usd: Option<Decimal>,
+    pub usd_foil: Option<Decimal>,
+    pub usd_etched: Option<Decimal>,
+    pub eur: Option<Decimal>,
+    pub eur_foil: Option<Decimal>,
+    pub tix: Option<Decimal>,
 }
 
-struct RelatedCard {
-    //   "object": "related_card",
-    //   "id": "2d33c84b-3807-430e-8315-5de2d7f6fa32",
-    //   "name": "Garbage Elemental",
-    //   "uri": "https://api.scryfall.com/cards/ust/82a"
+string_enum! {
+    /// Ordered from most to least common, so `Rarity::Rare > Rarity::Uncommon`
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub enum Rarity {
+        Common => "common",
+        Uncommon => "uncommon",
+        Rare => "rare",
+        Mythic => "mythic",
+        /// Timeshifted "purple" rarity and similar one-offs
+        Special => "special",
+        /// Bonus sheet cards, like Power Nine reprints in Vintage Masters
+        Bonus => "bonus",
+    }
 }
 
+string_enum! {
+    /// https://scryfall.com/docs/api/layouts
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum Layout {
+        /// A standard Magic card with one face
+        Normal => "normal",
+        /// A split-faced card
+        Split => "split",
+        /// Cards that invert vertically with the flip keyword
+        Flip => "flip",
+        /// Double-sided cards that transform
+        Transform => "transform",
+        /// Double-sided cards that can be played either-side
+        ModalDfc => "modal_dfc",
+        /// Cards with meld parts printed on the back
+        Meld => "meld",
+        /// Cards with Level Up
+        Leveler => "leveler",
+        /// Class-type enchantment cards
+        Class => "class",
+        /// Case-type enchantment cards
+        Case => "case",
+        /// Saga-type cards
+        Saga => "saga",
+        /// Cards with an Adventure spell part
+        Adventure => "adventure",
+        /// Cards with Mutate
+        Mutate => "mutate",
+        /// Cards with Prototype
+        Prototype => "prototype",
+        /// Battle-type cards
+        Battle => "battle",
+        /// Plane and Phenomenon-type cards
+        Planar => "planar",
+        /// Scheme-type cards
+        Scheme => "scheme",
+        /// Vanguard-type cards
+        Vanguard => "vanguard",
+        /// Token cards
+        Token => "token",
+        /// Tokens with another token printed on the back
+        DoubleFacedToken => "double_faced_token",
+        /// Emblem cards
+        Emblem => "emblem",
+        /// Cards with Augment
+        Augment => "augment",
+        /// Host-type cards
+        Host => "host",
+        /// Art Series collectable double-faced cards
+        ArtSeries => "art_series",
+        /// A Magic card with two sides that are unrelated
+        ReversibleCard => "reversible_card",
+    }
+}
+
+string_enum! {
+    /// https://scryfall.com/docs/api/frames
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum Frame {
+        /// The original Magic card frame, starting from Limited Edition Alpha.
+        Frame1993 => "1993",
+        /// The updated classic frame starting from Mirage block
+        Frame1997 => "1997",
+        /// The “modern” Magic card frame, introduced in Eighth Edition and Mirrodin block.
+        Frame2003 => "2003",
+        /// The holofoil-stamp Magic card frame, introduced in Magic 2015.
+        Frame2015 => "2015",
+        /// The frame used on cards from the future
+        Future => "future",
+    }
+}
+
+string_enum! {
+    /// https://scryfall.com/docs/api/frames
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum FrameEffect {
+        /// The cards have a legendary crown
+        Legendary => "legendary",
+        /// The miracle frame effect
+        Miracle => "miracle",
+        /// The Nyx-touched frame effect
+        Nyxtouched => "nyxtouched",
+        /// The draft-matters frame effect
+        Draft => "draft",
+        /// The Devoid frame effect
+        Devoid => "devoid",
+        /// The Odyssey tombstone mark
+        Tombstone => "tombstone",
+        /// A colorshifted frame
+        Colorshifted => "colorshifted",
+        /// The FNM-style inverted frame
+        Inverted => "inverted",
+        /// The sun and moon transform marks
+        SunMoonDfc => "sunmoondfc",
+        /// The compass and land transform marks
+        CompassLandDfc => "compasslanddfc",
+        /// The Origins and planeswalker transform marks
+        OriginPwDfc => "originpwdfc",
+        /// The moon and Eldrazi transform marks
+        MoonEldraziDfc => "mooneldrazidfc",
+        /// The waxing and waning crescent moon transform marks
+        WaxingAndWaningMoonDfc => "waxingandwaningmoondfc",
+        /// A custom Showcase frame
+        Showcase => "showcase",
+        /// An extended art frame
+        ExtendedArt => "extendedart",
+        /// The cards have a companion frame
+        Companion => "companion",
+        /// The cards have an etched foil treatment
+        Etched => "etched",
+        /// The cards have the snowy frame effect
+        Snow => "snow",
+        /// The cards have the Lesson frame effect
+        Lesson => "lesson",
+        /// The cards have the Shattered Glass frame effect
+        ShatteredGlass => "shatteredglass",
+        /// The cards have More Than Meets the Eye™ marks
+        ConvertDfc => "convertdfc",
+        /// The cards have fan transforming marks
+        FanDfc => "fandfc",
+        /// The cards have the Upside Down transforming marks
+        UpsideDownDfc => "upsidedowndfc",
+        /// The cards have the Spree frame effect
+        Spree => "spree",
+    }
+}
+
+string_enum! {
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum BorderColor {
+        Black => "black",
+        Borderless => "borderless",
+        Gold => "gold",
+        Silver => "silver",
+        White => "white",
+        Yellow => "yellow",
+    }
+}
+
+string_enum! {
+    /// A format Scryfall tracks legality for
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum Format {
+        Standard => "standard",
+        Future => "future",
+        Historic => "historic",
+        Timeless => "timeless",
+        Gladiator => "gladiator",
+        Pioneer => "pioneer",
+        Explorer => "explorer",
+        Modern => "modern",
+        Legacy => "legacy",
+        Pauper => "pauper",
+        Vintage => "vintage",
+        Penny => "penny",
+        Commander => "commander",
+        Oathbreaker => "oathbreaker",
+        StandardBrawl => "standardbrawl",
+        Brawl => "brawl",
+        Alchemy => "alchemy",
+        PauperCommander => "paupercommander",
+        Duel => "duel",
+        OldSchool => "oldschool",
+        Premodern => "premodern",
+        Predh => "predh",
+        /// Retired by Scryfall, but present in older responses
+        Frontier => "frontier",
+        /// Retired by Scryfall, but present in older responses
+        OneVsOne => "1v1",
+    }
+}
+
+string_enum! {
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum Legality {
+        Legal => "legal",
+        NotLegal => "not_legal",
+        /// Limited to one copy per deck
+        Restricted => "restricted",
+        Banned => "banned",
+    }
+}
+
+impl Card {
+    /// This card's legality in `format`, if Scryfall lists the format at all.
+    pub fn legality(&self, format: &Format) -> Option<&Legality> {
+        self.legalities.get(format)
+    }
+
+    /// True if this card can be played in `format`. Restricted cards count as legal.
+    pub fn is_legal_in(&self, format: Format) -> bool {
+        matches!(
+            self.legality(&format),
+            Some(&Legality::Legal) | Some(&Legality::Restricted)
+        )
+    }
+}
+
+/// Pages about a card on other Magic sites
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RelatedUris {
+    pub gatherer: Option<URI>,
+    pub tcgplayer_decks: Option<URI>,
+    pub edhrec: Option<URI>,
+    pub mtgtop8: Option<URI>,
+    /// Any site without a field of its own, by its Scryfall key
+    #[serde(flatten)]
+    pub other: HashMap<String, URI>,
+}
+
+/// Stores selling a card
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PurchaseUris {
+    pub tcgplayer: Option<URI>,
+    /// Called `magiccardmarket` in older responses
+    #[serde(alias = "magiccardmarket")]
+    pub cardmarket: Option<URI>,
+    pub cardhoarder: Option<URI>,
+    pub card_kingdom: Option<URI>,
+    pub coolstuffinc: Option<URI>,
+    /// Any vendor without a field of its own, by its Scryfall key
+    #[serde(flatten)]
+    pub other: HashMap<String, URI>,
+}
+
+/// One face of a multifaced card (split, flip, transform, meld, ...).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CardFace {
+    object: String, // Always set to 'card_face'
+    /// String The name of this particular face.
+    pub name: String,
+    /// UUID Nullable The Oracle ID of this particular face, if the card is reversible.
+    pub oracle_id: Option<OracleId>,
+    /// String The type line of this particular face.
+    pub type_line: Option<String>,
+    /// String Nullable The Oracle text for this face, if any.
+    pub oracle_text: Option<String>,
+    /// String The mana cost for this face. This value will be any empty string "" if the cost is absent. Remember that per the game rules, a missing mana cost and a mana cost of {0} are different values.
+    pub mana_cost: Option<String>,
+    /// Colors This face’s colors.
+    pub colors: Option<ColorSet>,
+    /// Colors Nullable The colors in this face’s color indicator, if any.
+    pub color_indicator: Option<ColorSet>,
+    /// String Nullable This face’s power, if any. Note that some cards have powers that are not numeric, such as *.
+    pub power: Option<String>,
+    /// String Nullable This face’s toughness, if any.
+    pub toughness: Option<String>,
+    /// String Nullable This face’s loyalty, if any.
+    pub loyalty: Option<String>,
+    /// String Nullable The flavor text printed on this face, if any.
+    pub flavor_text: Option<String>,
+    /// String Nullable The name of the illustrator of this card face. Newly spoiled cards may not have this field yet.
+    pub artist: Option<String>,
+    /// UUID Nullable A unique identifier for the card face artwork that remains consistent across reprints. Newly spoiled cards may not have this field yet.
+    pub illustration_id: Option<IllustrationId>,
+    /// Object Nullable An object providing URIs to imagery for this face, if this is a double-sided card. If this card is not double-sided, then the image_uris property will be part of the parent object instead.
+    pub image_uris: Option<Images>,
+}
+
+/// Per-face accessors that work the same whether a card keeps its text and imagery at the top
+/// level (normal cards), per face (transform, modal DFCs), or a mix of both (split, flip).
+impl Card {
+    /// This card's prices, from the `prices` object or the legacy top level fields.
+    pub fn prices(&self) -> Prices {
+        let mut prices = self.prices.clone().unwrap_or_default();
+        prices.usd = prices.usd.or(self.usd);
+        prices.eur = prices.eur.or(self.eur);
+        prices.tix = prices.tix.or(self.tix);
+        prices
+    }
+
+    /// How many faces this card has. Always at least 1.
+    pub fn face_count(&self) -> usize {
+        self.card_faces.as_ref().map_or(1, |faces| faces.len())
+    }
+
+    /// The name of face `index`, counting from 0.
+    pub fn face_name(&self, index: usize) -> Option<&str> {
+        match self.card_faces {
+            Some(ref faces) => faces.get(index).map(|face| face.name.as_str()),
+            None if index == 0 => Some(&self.name),
+            None => None,
+        }
+    }
+
+    /// The Oracle text of face `index`, counting from 0.
+    pub fn face_oracle_text(&self, index: usize) -> Option<&str> {
+        match self.card_faces {
+            Some(ref faces) => faces.get(index)?.oracle_text.as_deref(),
+            None if index == 0 => self.oracle_text.as_deref(),
+            None => None,
+        }
+    }
+
+    /// The mana cost of face `index`, counting from 0.
+    pub fn face_mana_cost(&self, index: usize) -> Option<&str> {
+        match self.card_faces {
+            Some(ref faces) => faces.get(index)?.mana_cost.as_deref(),
+            None if index == 0 => self.mana_cost.as_deref(),
+            None => None,
+        }
+    }
+
+    /// The imagery of face `index`, counting from 0. Split and flip cards print every face on
+    /// one image, so they all get the card's own `image_uris`.
+    pub fn face_image_uris(&self, index: usize) -> Option<&Images> {
+        match self.card_faces {
+            Some(ref faces) => {
+                let face = faces.get(index)?;
+                face.image_uris.as_ref().or(self.image_uris.as_ref())
+            }
+            None if index == 0 => self.image_uris.as_ref(),
+            None => None,
+        }
+    }
+
+    /// The URI of one version of one face's image. Faces that share the card's image (split,
+    /// flip, ...) all return the same URI.
+    pub fn image_uri(&self, version: ImageVersion, face: ImageFace) -> Option<&URI> {
+        self.face_image_uris(face.index())?.get(version.as_str())
+    }
+
+    /// The Oracle text of every face, separated by `\n//\n` like Scryfall's text exports.
+    pub fn full_oracle_text(&self) -> Option<String> {
+        let texts: Vec<&str> = (0..self.face_count())
+            .filter_map(|index| self.face_oracle_text(index))
+            .collect();
+        if texts.is_empty() {
+            None
+        } else {
+            Some(texts.join("\n//\n"))
+        }
+    }
+}
+
+/// A card closely related to another one: its tokens, meld partner, or combo pieces.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RelatedCard {
+    object: String, // Always set to 'related_card'
+    /// UUID An unique ID for this card in Scryfall’s database.
+    pub id: ScryfallId,
+    /// String A field explaining what role this card plays in this relationship. Older
+    /// responses (like Un-set meld parts from 2018) don't include it.
+    pub component: Option<RelatedCardComponent>,
+    /// String The name of this particular related card.
+    pub name: String,
+    /// String The type line of this card.
+    pub type_line: Option<String>,
+    /// URI A URI where you can retrieve a full object describing this card on Scryfall’s API.
+    pub uri: URI,
+}
+
+string_enum! {
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum RelatedCardComponent {
+        Token => "token",
+        MeldPart => "meld_part",
+        MeldResult => "meld_result",
+        ComboPiece => "combo_piece",
+    }
+}
+
+/// https://scryfall.com/docs/api/images
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ImageVersion {
+    /// A small full card image. Designed for use as thumbnail or list icon.
     Small,
+    /// A medium-sized full card image
     Normal,
+    /// A large full card image
     Large,
+    /// A transparent, rounded full card PNG. This is the best image to use for videos or other
+    /// high-quality content.
     Png,
+    /// A rectangular crop of the card’s art only. Not guaranteed to be perfect for cards with
+    /// outlier designs or strange frame arrangements
     ArtCrop,
+    /// A full card image with the rounded corners and the majority of the border cropped off.
+    /// Designed for dated contexts where rounded images can’t be used.
     BorderCrop,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ImageFace {
     Front,
     Back,
 }
 
+impl ImageVersion {
+    /// The key for this version in `image_uris`, and its `version` parameter on the API
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            ImageVersion::Small => "small",
+            ImageVersion::Normal => "normal",
+            ImageVersion::Large => "large",
+            ImageVersion::Png => "png",
+            ImageVersion::ArtCrop => "art_crop",
+            ImageVersion::BorderCrop => "border_crop",
+        }
+    }
+}
+
+impl ImageFace {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            ImageFace::Front => "front",
+            ImageFace::Back => "back",
+        }
+    }
+
+    /// Index of this face in `Card::card_faces`
+    pub fn index(&self) -> usize {
+        match *self {
+            ImageFace::Front => 0,
+            ImageFace::Back => 1,
+        }
+    }
+}
+
+/// The raw bytes of a card image (JPEG, or PNG for `ImageVersion::Png`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image(pub Vec<u8>);
+
+#[derive(Debug, Serialize, Deserialize)]
+/// https://scryfall.com/docs/api/rulings
+pub struct Ruling {
+    object: String, // Always set to 'ruling'
+    /// A unique ID for the oracle identity of the card this ruling is about.
+    pub oracle_id: OracleId,
+    /// A computer-readable string indicating which company produced this ruling, either wotc or
+    /// scryfall.
+    pub source: String,
+    /// The date when the ruling or note was published.
+    pub published_at: chrono::NaiveDate,
+    /// The text of the ruling.
+    pub comment: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+/// https://scryfall.com/docs/api/card-symbols
+pub struct CardSymbol {
+    object: String, // Always set to 'card_symbol'
+    /// The plaintext symbol. Often surrounded with curly braces {}. Note that not all symbols are
+    /// ASCII text (for example, {∞}).
+    pub symbol: String,
+    /// An alternate version of this symbol, if it is possible to write it without curly braces.
+    pub loose_variant: Option<String>,
+    /// An English snippet that describes this symbol. Appropriate for use in alt text or other
+    /// accessible communication formats.
+    pub english: String,
+    /// True if it is possible to write this symbol “backwards”. For example, the official symbol
+    /// {U/P} is sometimes written as {P/U} or {P\U} in informal settings.
+    pub transposable: bool,
+    /// True if this is a mana symbol.
+    pub represents_mana: bool,
+    /// A decimal number representing this symbol’s converted mana cost. Note that mana symbols
+    /// from funny sets can have fractional converted mana costs.
+    pub cmc: Option<f64>,
+    /// True if this symbol appears in a mana cost on any Magic card. For example {20} has this
+    /// field set to false because {20} only appears in Oracle text, not mana costs.
+    pub appears_in_mana_costs: bool,
+    /// True if this symbol is only used on funny cards or Un-cards.
+    pub funny: bool,
+    /// An array of colors that this symbol represents.
+    pub colors: ColorSet,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+/// https://scryfall.com/docs/api/card-symbols/parse-mana
+pub struct ManaCostParse {
+    object: String, // Always set to 'mana_cost'
+    /// The normalized cost, with correctly-ordered and wrapped mana symbols.
+    pub cost: String,
+    /// The converted mana cost. If you submit Un-set mana symbols, this decimal could include
+    /// fractional parts.
+    pub cmc: f64,
+    /// The colors of the given cost.
+    pub colors: ColorSet,
+    /// True if the cost is colorless.
+    pub colorless: bool,
+    /// True if the cost is monocolored.
+    pub monocolored: bool,
+    /// True if the cost is multicolored.
+    pub multicolored: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ListObject<T> {
     object: String, // Always set to 'list'
-    has_more: bool,
-    data: Vec<T>,
-    next_page: Option<String>,
+    pub(crate) has_more: bool,
+    pub(crate) data: Vec<T>,
+    pub(crate) next_page: Option<String>,
+    /// Card lists call this `total_cards`, other lists `total`
+    #[serde(alias = "total_cards")]
     total: Option<u32>,
     warnings: Option<Vec<String>>,
 }
 
+impl<T> ListObject<T> {
+    /// The items on this page
+    pub fn data(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn into_data(self) -> Vec<T> {
+        self.data
+    }
+
+    /// True if there is another page after this one
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+
+    /// URI of the next page, if there is one
+    pub fn next_page(&self) -> Option<&str> {
+        self.next_page.as_deref()
+    }
+
+    /// The number of items across every page, when Scryfall reports it.
+    pub fn total(&self) -> Option<u32> {
+        self.total
+    }
+
+    /// Non-fatal problems Scryfall had with the request, like search terms it ignored.
+    pub fn warnings(&self) -> &[String] {
+        self.warnings.as_deref().unwrap_or(&[])
+    }
+}
+
+/// https://scryfall.com/docs/api/bulk-data
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BulkDataItem {
+    object: String, // Always set to 'bulk_data'
+    pub id: ::uuid::Uuid,
+    /// Which file this is, like all unique Oracle cards or every printing
+    #[serde(rename = "type")]
+    pub bulk_type: BulkDataType,
+    /// When the file was last generated. Scryfall refreshes them every 24 hours.
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    /// This object on Scryfall's API
+    pub uri: URI,
+    pub name: String,
+    pub description: String,
+    /// Size of the file in bytes
+    pub size: u64,
+    /// Size of the file in bytes as transferred, if Scryfall still reports it
+    pub compressed_size: Option<u64>,
+    /// Where to download the file. Hand the item to `ScryfallApi::download_bulk_data`.
+    pub download_uri: URI,
+    pub content_type: String,
+    pub content_encoding: String,
+}
+
+string_enum! {
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum BulkDataType {
+        /// One card object for each Oracle ID, in its most recognizable printing
+        OracleCards => "oracle_cards",
+        /// One card object for each unique piece of art
+        UniqueArtwork => "unique_artwork",
+        /// Every printing of every card, in English or the only language it was printed in
+        DefaultCards => "default_cards",
+        /// Every printing of every card in every language
+        AllCards => "all_cards",
+        /// Every ruling, as ruling objects instead of cards
+        Rulings => "rulings",
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 /// https://scryfall.com/docs/api/catalogs
 /// TODO: Do I want public thingies?
@@ -219,32 +889,84 @@ pub struct Catalog {
     pub data: Vec<String>,
 }
 
-impl ScryfallResponse for Catalog {
-    fn parse(raw: &str) -> Catalog {
-        serde_json::from_str(raw).unwrap()
-    }
+#[derive(Debug, Serialize, Deserialize)]
+/// https://scryfall.com/docs/api/errors
+pub struct ErrorObject {
+    object: String, // Always set to 'error'
+    /// An integer HTTP status code for this error.
+    pub status: u16,
+    /// A computer-friendly string representing the appropriate HTTP status code.
+    pub code: String,
+    /// A human-readable string explaining the error.
+    pub details: String,
+    /// A computer-friendly string that provides additional context for the main error. For
+    /// example, an endpoint many generate HTTP 404 errors for different kinds of input. This
+    /// field will provide a label for the specific kind of 404 failure, such as ambiguous.
+    #[serde(rename = "type")]
+    pub error_type: Option<String>,
+    /// If your input also generated non-failure warnings, they will be provided as
+    /// human-readable strings in this array.
+    pub warnings: Option<Vec<String>>,
 }
 
 use serde::de::DeserializeOwned;
 
+/// Decode a Scryfall JSON body, turning an `"object": "error"` body into `ScryfallError::Api`
+fn parse_json<T>(raw: &[u8]) -> Result<T, ScryfallError>
+where
+    T: DeserializeOwned,
+{
+    let value: serde_json::Value = serde_json::from_slice(raw)?;
+    if value.get("object").and_then(|o| o.as_str()) == Some("error") {
+        return Err(ScryfallError::Api(Box::new(serde_json::from_value(value)?)));
+    }
+    Ok(serde_json::from_value(value)?)
+}
+
+impl ScryfallResponse for Catalog {
+    fn parse(raw: &[u8]) -> Result<Catalog, ScryfallError> {
+        parse_json(raw)
+    }
+}
+
 impl<T> ScryfallResponse for ListObject<T>
 where
     T: DeserializeOwned,
 {
-    fn parse(raw: &str) -> ListObject<T> {
-        serde_json::from_str(raw).unwrap()
+    fn parse(raw: &[u8]) -> Result<ListObject<T>, ScryfallError> {
+        parse_json(raw)
+    }
+
+    fn warnings(&self) -> &[String] {
+        ListObject::warnings(self)
     }
 }
 
 impl ScryfallResponse for Set {
-    fn parse(raw: &str) -> Set {
-        serde_json::from_str(raw).unwrap()
+    fn parse(raw: &[u8]) -> Result<Set, ScryfallError> {
+        parse_json(raw)
     }
 }
 
 impl ScryfallResponse for Card {
-    fn parse(raw: &str) -> Card {
-        serde_json::from_str(raw).unwrap()
+    fn parse(raw: &[u8]) -> Result<Card, ScryfallError> {
+        parse_json(raw)
+    }
+}
+
+impl ScryfallResponse for Image {
+    fn parse(raw: &[u8]) -> Result<Image, ScryfallError> {
+        // Missing images are reported with a regular JSON error object
+        if let Ok(error) = serde_json::from_slice::<ErrorObject>(raw) {
+            return Err(ScryfallError::Api(Box::new(error)));
+        }
+        Ok(Image(raw.to_vec()))
+    }
+}
+
+impl ScryfallResponse for ManaCostParse {
+    fn parse(raw: &[u8]) -> Result<ManaCostParse, ScryfallError> {
+        parse_json(raw)
     }
 }
 
@@ -262,13 +984,125 @@ mod tests {
     #[test]
     fn list_object_card_search_result_parse() {
         let json = include_str!("../testcases/card_search_result.json");
-        let _list: ListObject<Card> = serde_json::from_str(json).expect("Parse ListObject<Set>");
+        let list = ListObject::<Card>::parse(json.as_bytes()).expect("Parse ListObject<Card>");
+        assert_eq!(Some(410), list.total());
+        assert!(list.has_more());
+        assert!(list.next_page().unwrap().contains("page=2"));
+        assert_eq!(175, list.data().len());
+        assert!(list.warnings().is_empty());
+    }
+
+    #[test]
+    fn list_object_warnings_parse() {
+        let json = include_str!("../testcases/card_search_warnings.json");
+        let list = ListObject::<Card>::parse(json.as_bytes()).expect("Parse ListObject<Card>");
+        assert_eq!(Some(0), list.total());
+        assert_eq!(None, list.next_page());
+        assert_eq!(1, ScryfallResponse::warnings(&list).len());
+        assert!(list.warnings()[0].contains("is:shiny"));
+    }
+
+    #[test]
+    fn rulings_parse() {
+        let json = include_str!("../testcases/rulings.json");
+        let list = ListObject::<Ruling>::parse(json.as_bytes()).expect("Parse ListObject<Ruling>");
+        assert_eq!(2, list.data.len());
+        assert_eq!("scryfall", list.data[1].source);
+        assert_eq!(
+            chrono::NaiveDate::from_ymd_opt(2018, 4, 13),
+            Some(list.data[1].published_at)
+        );
+    }
+
+    #[test]
+    fn symbology_parse() {
+        let json = include_str!("../testcases/symbology.json");
+        let list =
+            ListObject::<CardSymbol>::parse(json.as_bytes()).expect("Parse ListObject<CardSymbol>");
+        let hybrid = list.data.iter().find(|s| s.symbol == "{W/U}").unwrap();
+        assert!(hybrid.transposable);
+        assert_eq!(Some("azorius"), hybrid.colors.name());
+        let chaos = list.data.iter().find(|s| s.symbol == "{CHAOS}").unwrap();
+        assert_eq!(None, chaos.cmc);
+    }
+
+    #[test]
+    fn current_card_parse() {
+        let json = include_str!("../testcases/card_current.json");
+        let card = Card::parse(json.as_bytes()).expect("Parse current Card JSON");
+        assert!(!card.timeshifted && !card.colorshifted && !card.futureshifted);
+        assert_eq!(None, card.frame_effects);
+        assert_eq!(Some("0.10".parse().unwrap()), card.prices().usd);
+
+        let mut value: serde_json::Value = serde_json::from_str(json).unwrap();
+        value["frame_effects"] = serde_json::json!(["colorshifted", "someday"]);
+        let card: Card = serde_json::from_value(value).expect("Parse Card with frame effects");
+        assert_eq!(
+            Some(vec![
+                FrameEffect::Colorshifted,
+                FrameEffect::Unknown("someday".to_string())
+            ]),
+            card.frame_effects
+        );
+    }
+
+    #[test]
+    fn bulk_data_parse() {
+        let json = include_str!("../testcases/bulk_data.json");
+        let list = ListObject::<BulkDataItem>::parse(json.as_bytes())
+            .expect("Parse ListObject<BulkDataItem>");
+        let oracle = &list.data()[0];
+        assert_eq!(BulkDataType::OracleCards, oracle.bulk_type);
+        assert_eq!(161384563, oracle.size);
+        assert_eq!(None, oracle.compressed_size);
+        assert_eq!(
+            chrono::NaiveDate::from_ymd_opt(2024, 6, 5),
+            Some(oracle.updated_at.date_naive())
+        );
+        assert_eq!(Some(34867328), list.data()[1].compressed_size);
+        assert_eq!(BulkDataType::Rulings, list.data()[2].bulk_type);
+    }
+
+    #[test]
+    fn parse_mana_parse() {
+        let json = include_str!("../testcases/parse_mana.json");
+        let cost = ManaCostParse::parse(json.as_bytes()).expect("Parse ManaCostParse");
+        assert_eq!("{X}{R}{R}{G}", cost.cost);
+        assert_eq!(3.0, cost.cmc);
+        assert!(cost.multicolored);
     }
 
     #[test]
     fn set_parse() {
         let json = include_str!("../testcases/set.json");
-        let _set: Set = serde_json::from_str(json).expect("Parse Set JSON");
+        let set: Set = serde_json::from_str(json).expect("Parse Set JSON");
+        assert_eq!(SetType::Spellbook, set.set_type);
+        assert_eq!(
+            chrono::NaiveDate::from_ymd_opt(2018, 6, 15),
+            set.released_at
+        );
+    }
+
+    #[test]
+    fn expansions_released_after_2015() {
+        let json = include_str!("../testcases/list_object_sets.json");
+        let list: ListObject<Set> = serde_json::from_str(json).expect("Parse ListObject<Set>");
+        let cutoff = chrono::NaiveDate::from_ymd_opt(2015, 12, 31).unwrap();
+        let recent: Vec<&str> = list
+            .data
+            .iter()
+            .filter(|set| set.set_type == SetType::Expansion)
+            .filter(|set| set.released_at.is_some_and(|date| date > cutoff))
+            .map(|set| set.code.as_str())
+            .collect();
+        assert_eq!(
+            vec!["dom", "rix", "xln", "hou", "akh", "aer", "kld", "emn", "soi", "ogw"],
+            recent
+        );
+        assert!(list
+            .data
+            .iter()
+            .all(|set| !matches!(set.set_type, SetType::Unknown(_))));
     }
 
     #[test]
@@ -276,4 +1110,251 @@ mod tests {
         let json = include_str!("../testcases/card.json");
         let _card: Card = serde_json::from_str(json).expect("Parse Card JSON");
     }
+
+    #[test]
+    fn card_enums_parse() {
+        let json = include_str!("../testcases/card.json");
+        let card = Card::parse(json.as_bytes()).expect("Parse Card JSON");
+        assert_eq!(Rarity::Uncommon, card.rarity);
+        assert_eq!(Layout::Normal, card.layout);
+        assert_eq!(Frame::Frame2015, card.frame);
+        assert_eq!(BorderColor::Black, card.border_color);
+
+        let json = include_str!("../testcases/card_transform.json");
+        let card = Card::parse(json.as_bytes()).expect("Parse transform Card JSON");
+        assert_eq!(Layout::Transform, card.layout);
+    }
+
+    #[test]
+    fn card_ids_parse() {
+        let json = include_str!("../testcases/card.json");
+        let card = Card::parse(json.as_bytes()).expect("Parse Card JSON");
+        assert_eq!("8cae1a42-052e-4110-9afc-d3ec83b7c8a9", card.id.to_string());
+        assert_eq!(
+            Ok(card.oracle_id),
+            "67362406-b1ca-49e2-800d-9050bfe8742a".parse().map(Some)
+        );
+        assert_eq!(Some(vec![MultiverseId(442054)]), card.multiverse_ids);
+        assert_eq!(Some(MtgoId(67044)), card.mtgo_id);
+        assert!(card.illustration_id.is_some());
+        assert_eq!(Some(65), card.collector_number.number());
+        assert!("a25/65".parse::<ScryfallId>().is_err());
+
+        // Reversible cards only have Oracle IDs on their faces
+        let mut value: serde_json::Value = serde_json::from_str(json).unwrap();
+        value.as_object_mut().unwrap().remove("oracle_id");
+        let card: Card = serde_json::from_value(value).expect("Parse Card without oracle_id");
+        assert_eq!(None, card.oracle_id);
+    }
+
+    #[test]
+    fn legalities_parse() {
+        let json = include_str!("../testcases/card.json");
+        let card = Card::parse(json.as_bytes()).expect("Parse Card JSON");
+        assert!(card.is_legal_in(Format::Pauper));
+        assert!(!card.is_legal_in(Format::Standard));
+        assert!(!card.is_legal_in(Format::Pioneer));
+        assert_eq!(Some(&Legality::Legal), card.legality(&Format::OneVsOne));
+        assert_eq!(Some(&Legality::NotLegal), card.legality(&Format::Brawl));
+
+        let json = include_str!("../testcases/card_all_parts.json");
+        let card = Card::parse(json.as_bytes()).expect("Parse Card JSON");
+        assert!(!card.is_legal_in(Format::Pauper));
+    }
+
+    #[test]
+    fn card_enums_unknown_values() {
+        let layout: Layout = serde_json::from_str("\"hologram\"").expect("Parse Layout");
+        assert_eq!(Layout::Unknown("hologram".to_string()), layout);
+        assert_eq!("\"hologram\"", serde_json::to_string(&layout).unwrap());
+        assert_eq!("modal_dfc", Layout::ModalDfc.to_string());
+    }
+
+    #[test]
+    fn rarity_ordering() {
+        assert!(Rarity::Common < Rarity::Uncommon);
+        assert!(Rarity::Rare < Rarity::Mythic);
+        assert!(Rarity::Mythic < Rarity::Special);
+        assert!(Rarity::Mythic < Rarity::Bonus);
+    }
+
+    #[test]
+    fn legacy_prices_parse() {
+        let json = include_str!("../testcases/card.json");
+        let card = Card::parse(json.as_bytes()).expect("Parse Card JSON");
+        let prices = card.prices();
+        assert_eq!(Some("0.07".parse().unwrap()), prices.usd);
+        assert_eq!(Some("0.05".parse().unwrap()), prices.eur);
+        assert_eq!(Some("0.02".parse().unwrap()), prices.tix);
+        assert_eq!(None, prices.usd_foil);
+    }
+
+    #[test]
+    fn nested_prices_parse() {
+        let json = include_str!("../testcases/card_all_parts.json");
+        let card = Card::parse(json.as_bytes()).expect("Parse Card JSON");
+        let prices = card.prices();
+        assert_eq!(Some("0.79".parse().unwrap()), prices.usd);
+        assert_eq!(Some("3.49".parse().unwrap()), prices.usd_foil);
+        assert_eq!(None, prices.usd_etched);
+        assert_eq!(Some("1.90".parse().unwrap()), prices.eur_foil);
+        // Exact, unlike 0.79 + 3.49 as f64
+        assert_eq!(
+            "4.28".parse::<Decimal>().unwrap(),
+            prices.usd.unwrap() + prices.usd_foil.unwrap()
+        );
+    }
+
+    #[test]
+    fn related_and_purchase_uris_parse() {
+        let json = include_str!("../testcases/card.json");
+        let card = Card::parse(json.as_bytes()).expect("Parse Card JSON");
+
+        let related = card.related_uris.expect("related_uris");
+        assert_eq!(
+            Some("http://edhrec.com/route/?cc=Merfolk+Looter".to_string()),
+            related.edhrec
+        );
+        assert!(related.other.is_empty());
+
+        let purchase = card.purchase_uris.expect("purchase_uris");
+        assert_eq!(
+            Some("https://scryfall.com/s/mcm/319234".to_string()),
+            purchase.cardmarket
+        );
+        assert!(purchase.cardhoarder.is_some());
+        assert!(purchase.other.contains_key("amazon"));
+        assert!(purchase.other.contains_key("mtgo_traders"));
+        assert!(!purchase.other.contains_key("tcgplayer"));
+    }
+
+    #[test]
+    fn card_faces_parse() {
+        let json = include_str!("../testcases/card_transform.json");
+        let card = Card::parse(json.as_bytes()).expect("Parse transform Card JSON");
+        assert_eq!(2, card.face_count());
+        assert_eq!(Some("Insectile Aberration"), card.face_name(1));
+        assert_eq!(Some("Flying"), card.face_oracle_text(1));
+        assert_eq!(Some(""), card.face_mana_cost(1));
+        assert!(card.face_image_uris(1).unwrap()["normal"].contains("51b.jpg"));
+        assert_eq!(None, card.face_name(2));
+    }
+
+    #[test]
+    fn split_card_faces_share_image() {
+        let json = include_str!("../testcases/card_split.json");
+        let card = Card::parse(json.as_bytes()).expect("Parse split Card JSON");
+        assert_eq!(Some("{1}{U}"), card.face_mana_cost(1));
+        assert_eq!(card.image_uris.as_ref(), card.face_image_uris(1));
+        assert_eq!(
+            Some("Fire deals 2 damage divided as you choose among one or two targets.\n//\nTap target permanent.\nDraw a card.".to_string()),
+            card.full_oracle_text()
+        );
+    }
+
+    #[test]
+    fn card_colors_parse() {
+        let json = include_str!("../testcases/card_split.json");
+        let fire_ice = Card::parse(json.as_bytes()).expect("Parse split Card JSON");
+        assert_eq!("UR", fire_ice.color_identity.to_string());
+        assert_eq!(Some("izzet"), fire_ice.color_identity.name());
+
+        let json = include_str!("../testcases/card_transform.json");
+        let delver = Card::parse(json.as_bytes()).expect("Parse transform Card JSON");
+        let faces = delver.card_faces.as_ref().unwrap();
+        assert_eq!(Some(ColorSet::from(Color::Blue)), faces[1].color_indicator);
+        assert!(delver.color_identity.is_subset(fire_ice.color_identity));
+        assert!(!fire_ice.color_identity.is_subset(delver.color_identity));
+    }
+
+    #[test]
+    fn card_image_uri() {
+        let json = include_str!("../testcases/card_transform.json");
+        let card = Card::parse(json.as_bytes()).expect("Parse transform Card JSON");
+        assert_eq!(
+            Some(&"https://img.scryfall.com/cards/art_crop/en/isd/51b.jpg?1526228315".to_string()),
+            card.image_uri(ImageVersion::ArtCrop, ImageFace::Back)
+        );
+
+        let json = include_str!("../testcases/card.json");
+        let card = Card::parse(json.as_bytes()).expect("Parse Card JSON");
+        assert_eq!(
+            Some(&"https://img.scryfall.com/cards/png/en/a25/65.png?1521725642".to_string()),
+            card.image_uri(ImageVersion::Png, ImageFace::Front)
+        );
+        assert_eq!(None, card.image_uri(ImageVersion::Png, ImageFace::Back));
+    }
+
+    #[test]
+    fn image_parse() {
+        let png = b"\x89PNG\r\n\x1a\n";
+        assert_eq!(
+            Ok(Image(png.to_vec())),
+            Image::parse(png).map_err(|e| e.to_string())
+        );
+
+        let json = include_str!("../testcases/error.json");
+        match Image::parse(json.as_bytes()) {
+            Err(ScryfallError::Api(e)) => assert_eq!("not_found", e.code),
+            other => panic!("Expected an API error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn single_faced_card_helpers() {
+        let json = include_str!("../testcases/card.json");
+        let card = Card::parse(json.as_bytes()).expect("Parse Card JSON");
+        assert_eq!(1, card.face_count());
+        assert_eq!(
+            Some("{T}: Draw a card, then discard a card."),
+            card.face_oracle_text(0)
+        );
+        assert_eq!(None, card.face_oracle_text(1));
+        assert!(card.face_image_uris(0).is_some());
+    }
+
+    #[test]
+    fn all_parts_parse() {
+        let json = include_str!("../testcases/card_all_parts.json");
+        let card = Card::parse(json.as_bytes()).expect("Parse Card JSON with all_parts");
+        let parts = card.all_parts.expect("all_parts");
+        assert_eq!(2, parts.len());
+        assert_eq!(Some(RelatedCardComponent::ComboPiece), parts[0].component);
+        assert_eq!(Some(RelatedCardComponent::Token), parts[1].component);
+        assert_eq!("Elemental", parts[1].name);
+
+        let part: RelatedCard = serde_json::from_value(serde_json::json!({
+            "object": "related_card",
+            "id": "0b61d772-2d8b-4acf-9dd2-b2e8b03538c8",
+            "component": "attraction",
+            "name": "Balloon Stand",
+            "uri": "https://api.scryfall.com/cards/0b61d772-2d8b-4acf-9dd2-b2e8b03538c8"
+        }))
+        .expect("Parse RelatedCard with a new component");
+        assert_eq!(
+            Some(RelatedCardComponent::Unknown("attraction".to_string())),
+            part.component
+        );
+    }
+
+    #[test]
+    fn error_object_parse() {
+        let json = include_str!("../testcases/error.json");
+        match Card::parse(json.as_bytes()) {
+            Err(ScryfallError::Api(e)) => {
+                assert_eq!(404, e.status);
+                assert_eq!("not_found", e.code);
+                assert_eq!(Some("ambiguous".to_string()), e.error_type);
+            }
+            other => panic!("Expected an API error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn malformed_json_parse() {
+        match Set::parse(b"{\"object\": \"set\"") {
+            Err(ScryfallError::Json(_)) => (),
+            other => panic!("Expected a JSON error, got {:?}", other),
+        }
+    }
 }